@@ -185,6 +185,20 @@ fn evaluation_is_concurrent(&self) -> bool {
         self.sess.threads() > 1
     }
 
+    fn with_profiled_activity<R>(
+        self,
+        label: &'static str,
+        def_id: DefId,
+        f: impl FnOnce() -> R,
+    ) -> R {
+        let _timer = self
+            .prof
+            .generic_activity_with_arg_recorder(label, |recorder| {
+                recorder.record_arg(ty::print::with_no_trimmed_paths!(self.def_path_str(def_id)))
+            });
+        f()
+    }
+
     fn expand_abstract_consts<T: TypeFoldable<TyCtxt<'tcx>>>(self, t: T) -> T {
         self.expand_abstract_consts(t)
     }
@@ -106,5 +106,6 @@ fn is_transmutable(
         dst: <Self::Interner as Interner>::Ty,
         src: <Self::Interner as Interner>::Ty,
         assume: <Self::Interner as Interner>::Const,
+        goals: &mut Vec<Goal<Self::Interner, <Self::Interner as Interner>::Predicate>>,
     ) -> Result<Certainty, NoSolution>;
 }
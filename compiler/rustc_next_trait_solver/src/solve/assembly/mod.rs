@@ -309,15 +309,30 @@ pub(super) fn assemble_and_evaluate_candidates<G: GoalKind<D>>(
                 return vec![candidate];
             }
         }
-        self.assemble_impl_candidates(goal, &mut candidates);
 
-        self.assemble_builtin_impl_candidates(goal, &mut candidates);
+        // Each kind of candidate is assembled in its own self-profile activity,
+        // with the trait as the event argument, to see where solver time goes.
+        let cx = self.cx();
+        let trait_def_id = goal.predicate.trait_def_id(cx);
+        cx.with_profiled_activity("solve_assemble_impl_candidates", trait_def_id, || {
+            self.assemble_impl_candidates(goal, &mut candidates)
+        });
+
+        cx.with_profiled_activity("solve_assemble_builtin_impl_candidates", trait_def_id, || {
+            self.assemble_builtin_impl_candidates(goal, &mut candidates)
+        });
 
-        self.assemble_alias_bound_candidates(goal, &mut candidates);
+        cx.with_profiled_activity("solve_assemble_alias_bound_candidates", trait_def_id, || {
+            self.assemble_alias_bound_candidates(goal, &mut candidates)
+        });
 
-        self.assemble_object_bound_candidates(goal, &mut candidates);
+        cx.with_profiled_activity("solve_assemble_object_bound_candidates", trait_def_id, || {
+            self.assemble_object_bound_candidates(goal, &mut candidates)
+        });
 
-        self.assemble_param_env_candidates(goal, &mut candidates);
+        cx.with_profiled_activity("solve_assemble_param_env_candidates", trait_def_id, || {
+            self.assemble_param_env_candidates(goal, &mut candidates)
+        });
 
         if self.solver_mode() == SolverMode::Normal {
             self.discard_impls_shadowed_by_env(goal, &mut candidates);
@@ -341,7 +356,14 @@ pub(super) fn forced_ambiguity(
             .enter(|this| this.evaluate_added_goals_and_make_canonical_response(certainty))
     }
 
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            trait_def_id = ?goal.predicate.trait_def_id(self.cx()),
+            self_ty = ?goal.predicate.self_ty(),
+        )
+    )]
     fn assemble_impl_candidates<G: GoalKind<D>>(
         &mut self,
         goal: Goal<I, G>,
@@ -367,7 +389,14 @@ fn assemble_impl_candidates<G: GoalKind<D>>(
         );
     }
 
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            trait_def_id = ?goal.predicate.trait_def_id(self.cx()),
+            self_ty = ?goal.predicate.self_ty(),
+        )
+    )]
     fn assemble_builtin_impl_candidates<G: GoalKind<D>>(
         &mut self,
         goal: Goal<I, G>,
@@ -477,7 +506,14 @@ fn assemble_builtin_impl_candidates<G: GoalKind<D>>(
         }
     }
 
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            trait_def_id = ?goal.predicate.trait_def_id(self.cx()),
+            self_ty = ?goal.predicate.self_ty(),
+        )
+    )]
     fn assemble_param_env_candidates<G: GoalKind<D>>(
         &mut self,
         goal: Goal<I, G>,
@@ -494,7 +530,14 @@ fn assemble_param_env_candidates<G: GoalKind<D>>(
         }
     }
 
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            trait_def_id = ?goal.predicate.trait_def_id(self.cx()),
+            self_ty = ?goal.predicate.self_ty(),
+        )
+    )]
     fn assemble_alias_bound_candidates<G: GoalKind<D>>(
         &mut self,
         goal: Goal<I, G>,
@@ -570,15 +613,24 @@ fn assemble_alias_bound_candidates_recur<G: GoalKind<D>>(
             }
         };
 
-        for assumption in
-            self.cx().item_bounds(alias_ty.def_id).iter_instantiated(self.cx(), alias_ty.args)
-        {
+        let cx = self.cx();
+        for assumption in cx.item_bounds(alias_ty.def_id).iter_instantiated(cx, alias_ty.args) {
+            // The item bounds of a GAT only hold if its own where-clauses hold as well,
+            // so we require them when using any of its alias bounds. They are only
+            // instantiated and added as nested goals if the assumption matches the goal.
+            let gat_where_clauses = (kind == ty::Projection)
+                .then(|| {
+                    cx.own_predicates_of(alias_ty.def_id).iter_instantiated(cx, alias_ty.args)
+                })
+                .into_iter()
+                .flatten()
+                .map(|pred| (GoalSource::Misc, goal.with(cx, pred)));
             candidates.extend(G::probe_and_consider_implied_clause(
                 self,
                 CandidateSource::AliasBound,
                 goal,
                 assumption,
-                [],
+                gat_where_clauses,
             ));
         }
 
@@ -595,7 +647,14 @@ fn assemble_alias_bound_candidates_recur<G: GoalKind<D>>(
         }
     }
 
-    #[instrument(level = "trace", skip_all)]
+    #[instrument(
+        level = "trace",
+        skip_all,
+        fields(
+            trait_def_id = ?goal.predicate.trait_def_id(self.cx()),
+            self_ty = ?goal.predicate.self_ty(),
+        )
+    )]
     fn assemble_object_bound_candidates<G: GoalKind<D>>(
         &mut self,
         goal: Goal<I, G>,
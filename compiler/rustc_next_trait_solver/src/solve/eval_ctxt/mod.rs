@@ -997,7 +997,10 @@ pub(super) fn is_transmutable(
         src: I::Ty,
         assume: I::Const,
     ) -> Result<Certainty, NoSolution> {
-        self.delegate.is_transmutable(param_env, dst, src, assume)
+        let mut goals = Vec::new();
+        let certainty = self.delegate.is_transmutable(param_env, dst, src, assume, &mut goals)?;
+        self.add_goals(GoalSource::ImplWhereBound, goals);
+        Ok(certainty)
     }
 }
 
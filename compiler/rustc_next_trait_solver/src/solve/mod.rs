@@ -244,18 +244,20 @@ fn try_merge_responses(
     }
 
     /// If we fail to merge responses we flounder and return overflow or ambiguity.
+    ///
+    /// We only return overflow if all responses overflowed. Candidates which hold
+    /// but could not be merged with the other responses are treated as ambiguous.
     #[instrument(level = "trace", skip(self), ret)]
     fn flounder(&mut self, responses: &[CanonicalResponse<I>]) -> QueryResult<I> {
-        if responses.is_empty() {
-            return Err(NoSolution);
-        }
-
-        let Certainty::Maybe(maybe_cause) =
-            responses.iter().fold(Certainty::AMBIGUOUS, |certainty, response| {
-                certainty.unify_with(response.value.certainty)
+        let Some(maybe_cause) = responses
+            .iter()
+            .map(|response| match response.value.certainty {
+                Certainty::Yes => MaybeCause::Ambiguity,
+                Certainty::Maybe(cause) => cause,
             })
+            .reduce(MaybeCause::or)
         else {
-            panic!("expected flounder response to be ambiguous")
+            return Err(NoSolution);
         };
 
         Ok(self.make_ambiguous_response_no_constraints(maybe_cause))
@@ -1,5 +1,5 @@
-//! Computes a normalizes-to (projection) goal for inherent associated types,
-//! `#![feature(lazy_type_alias)]` and `#![feature(type_alias_impl_trait)]`.
+//! Computes a normalizes-to (projection) goal for weak type aliases,
+//! i.e. type aliases under `#![feature(lazy_type_alias)]`.
 //!
 //! Since a weak alias is never ambiguous, this just computes the `type_of` of
 //! the alias and registers the where-clauses of the type alias.
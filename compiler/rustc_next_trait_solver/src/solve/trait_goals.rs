@@ -634,8 +634,8 @@ fn consider_builtin_transmute_candidate(
             return Err(NoSolution);
         }
 
-        // FIXME: This actually should destructure the `Result` we get from transmutability and
-        // register candidates. We probably need to register >1 since we may have an OR of ANDs.
+        // FIXME: `Answer::IfAny` conditions are currently treated as a conjunction. We
+        // probably need to register >1 candidate since we may have an OR of ANDs.
         ecx.probe_builtin_trait_candidate(BuiltinImplSource::Misc).enter(|ecx| {
             let certainty = ecx.is_transmutable(
                 goal.param_env,
@@ -172,6 +172,7 @@
         CallRefFuture,
         Capture,
         Center,
+        Child,
         Cleanup,
         Clone,
         CoerceUnsized,
@@ -4,9 +4,11 @@
 mod fulfill;
 pub mod inspect;
 mod normalize;
+mod root;
 mod select;
 
 pub use fulfill::{FulfillmentCtxt, NextSolverError};
 pub(crate) use normalize::deeply_normalize_for_diagnostics;
 pub use normalize::{deeply_normalize, deeply_normalize_with_skipped_universes};
+pub use root::{InferCtxtEvalExt, RootGoalEvaluation};
 pub use select::InferCtxtSelectExt;
@@ -1,7 +1,8 @@
 use std::ops::Deref;
 
-use rustc_data_structures::fx::FxHashSet;
+use rustc_data_structures::fx::{FxHashMap, FxHashSet};
 use rustc_hir::def_id::DefId;
+use rustc_hir::{LangItem, Mutability};
 use rustc_infer::infer::canonical::query_response::make_query_region_constraints;
 use rustc_infer::infer::canonical::{
     Canonical, CanonicalExt as _, CanonicalVarInfo, CanonicalVarValues,
@@ -130,13 +131,21 @@ fn make_deduplicated_outlives_constraints(
 
         assert_eq!(region_constraints.member_constraints, vec![]);
 
+        // Also drop region constraints which trivially hold, i.e. `'a: 'a` and `'static: 'a`,
+        // as they only bloat the canonical response.
         let mut seen = FxHashSet::default();
-        region_constraints
+        let mut outlives: Vec<_> = region_constraints
             .outlives
             .into_iter()
+            .filter(|&(ty::OutlivesPredicate(arg, r), _)| match arg.unpack() {
+                ty::GenericArgKind::Lifetime(sup) => sup != r && !sup.is_static(),
+                ty::GenericArgKind::Type(_) | ty::GenericArgKind::Const(_) => true,
+            })
             .filter(|&(outlives, _)| seen.insert(outlives))
             .map(|(outlives, _)| outlives)
-            .collect()
+            .collect();
+        transitively_reduce_region_outlives(&mut outlives);
+        outlives
     }
 
     fn instantiate_canonical<V>(
@@ -229,23 +238,126 @@ fn is_transmutable(
         dst: Ty<'tcx>,
         src: Ty<'tcx>,
         assume: ty::Const<'tcx>,
+        goals: &mut Vec<Goal<'tcx, ty::Predicate<'tcx>>>,
     ) -> Result<Certainty, NoSolution> {
-        // Erase regions because we compute layouts in `rustc_transmute`,
-        // which will ICE for region vars.
-        let (dst, src) = self.tcx.erase_regions((dst, src));
-
-        let Some(assume) = rustc_transmute::Assume::from_const(self.tcx, param_env, assume) else {
+        // We don't erase regions here: `rustc_transmute` erases them itself before computing
+        // layouts, and we need the regions of references to require `'src: 'dst` below.
+        let Some(assume_val) = rustc_transmute::Assume::from_const(self.tcx, param_env, assume)
+        else {
             return Err(NoSolution);
         };
 
-        // FIXME(transmutability): This really should be returning nested goals for `Answer::If*`
         match rustc_transmute::TransmuteTypeEnv::new(&self.0).is_transmutable(
             ObligationCause::dummy(),
             rustc_transmute::Types { src, dst },
-            assume,
+            assume_val,
         ) {
             rustc_transmute::Answer::Yes => Ok(Certainty::Yes),
-            rustc_transmute::Answer::No(_) | rustc_transmute::Answer::If(_) => Err(NoSolution),
+            rustc_transmute::Answer::If(cond) => {
+                self.transmute_condition_goals(param_env, cond, assume, assume_val, goals);
+                Ok(Certainty::Yes)
+            }
+            rustc_transmute::Answer::No(_) => Err(NoSolution),
+        }
+    }
+}
+
+impl<'tcx> SolverDelegate<'tcx> {
+    /// Flatten the `Condition` tree returned by `rustc_transmute` into a conjunction
+    /// of nested goals. This mirrors `confirm_transmutability_candidate` in the old solver.
+    fn transmute_condition_goals(
+        &self,
+        param_env: ty::ParamEnv<'tcx>,
+        cond: rustc_transmute::Condition<rustc_transmute::layout::rustc::Ref<'tcx>>,
+        assume: ty::Const<'tcx>,
+        assume_val: rustc_transmute::Assume,
+        goals: &mut Vec<Goal<'tcx, ty::Predicate<'tcx>>>,
+    ) {
+        match cond {
+            // FIXME(transmutability): Add separate `IfAny` case, instead of treating as `IfAll`.
+            // Not possible until the trait solver supports disjunctions of goals.
+            rustc_transmute::Condition::IfAll(conds) | rustc_transmute::Condition::IfAny(conds) => {
+                for cond in conds {
+                    self.transmute_condition_goals(param_env, cond, assume, assume_val, goals);
+                }
+            }
+            rustc_transmute::Condition::IfTransmutable { src, dst } => {
+                let tcx = self.tcx;
+                let transmute_def_id = tcx.require_lang_item(LangItem::TransmuteTrait, None);
+                let transmute_goal = |src: Ty<'tcx>, dst: Ty<'tcx>| {
+                    Goal::new(
+                        tcx,
+                        param_env,
+                        ty::TraitRef::new(
+                            tcx,
+                            transmute_def_id,
+                            [ty::GenericArg::from(dst), src.into(), assume.into()],
+                        ),
+                    )
+                };
+                let outlives_goal = |a: ty::Region<'tcx>, b: ty::Region<'tcx>| {
+                    Goal::new(tcx, param_env, ty::OutlivesPredicate(a, b))
+                };
+
+                // Given a transmutation from `&'src (mut) Src` and `&'dst (mut) Dst`,
+                // it is always the case that `Src` must be transmutable into `Dst`,
+                // and that `'src` must outlive `'dst`.
+                goals.push(transmute_goal(src.ty, dst.ty));
+                if !assume_val.lifetimes {
+                    goals.push(outlives_goal(src.lifetime, dst.lifetime));
+                }
+
+                // Given a transmutation from `&Src`, both `Src` and `Dst` must be
+                // `Freeze`, otherwise, using the transmuted value could lead to
+                // data races.
+                if src.mutability == Mutability::Not {
+                    let freeze_def_id = tcx.require_lang_item(LangItem::Freeze, None);
+                    goals.extend([src.ty, dst.ty].map(|ty| {
+                        Goal::new(tcx, param_env, ty::TraitRef::new(tcx, freeze_def_id, [ty]))
+                    }));
+                }
+
+                // Given a transmutation into `&'dst mut Dst`, it also must be the case
+                // that `Dst` is transmutable into `Src`, and that `'dst` lives exactly
+                // as long as `'src`.
+                if dst.mutability == Mutability::Mut {
+                    goals.push(transmute_goal(dst.ty, src.ty));
+                    if !assume_val.lifetimes {
+                        goals.push(outlives_goal(dst.lifetime, src.lifetime));
+                    }
+                }
+            }
         }
     }
 }
+
+/// Removes the region outlives constraints implied by two other constraints, i.e. `'a: 'c`
+/// if both `'a: 'b` and `'b: 'c` are present. Constraints are removed one at a time and only
+/// if they are implied by the remaining ones, so this is also correct for cycles like
+/// `'a: 'b`, `'b: 'a`, `'a: 'c` and `'b: 'c`, where only one of the last two is removed.
+fn transitively_reduce_region_outlives<'tcx>(
+    outlives: &mut Vec<ty::OutlivesPredicate<'tcx, ty::GenericArg<'tcx>>>,
+) {
+    let mut edges = FxHashSet::default();
+    let mut successors: FxHashMap<_, Vec<_>> = FxHashMap::default();
+    for &ty::OutlivesPredicate(arg, sub) in outlives.iter() {
+        if let Some(sup) = arg.as_region() {
+            edges.insert((sup, sub));
+            successors.entry(sup).or_default().push(sub);
+        }
+    }
+
+    outlives.retain(|&ty::OutlivesPredicate(arg, sub)| {
+        let Some(sup) = arg.as_region() else {
+            return true;
+        };
+        // `successors` is never updated, so check that both edges are still present.
+        let implied = successors[&sup].iter().any(|&mid| {
+            mid != sub && edges.contains(&(sup, mid)) && edges.contains(&(mid, sub))
+        });
+        if implied {
+            edges.remove(&(sup, sub));
+        }
+        !implied
+    });
+}
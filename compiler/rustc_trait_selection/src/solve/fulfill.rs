@@ -140,6 +140,12 @@ fn register_predicate_obligation(
     }
 
     fn collect_remaining_errors(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
+        if infcx.tcx.sess.opts.unstable_opts.dump_solver_proof_trees {
+            for obligation in &self.obligations.pending {
+                inspect::dump_proof_tree(infcx, obligation.clone().into());
+            }
+        }
+
         self.obligations
             .pending
             .drain(..)
@@ -171,6 +177,13 @@ fn select_where_possible(&mut self, infcx: &InferCtxt<'tcx>) -> Vec<E> {
                     .evaluate_root_goal(goal, GenerateProofTree::No)
                     .0;
                 self.inspect_evaluated_obligation(infcx, &obligation, &result);
+                // Ambiguous goals are evaluated again in the next iteration, so only dump
+                // the proof tree once the goal has been resolved.
+                if infcx.tcx.sess.opts.unstable_opts.dump_solver_proof_trees
+                    && !matches!(result, Ok((_, Certainty::Maybe(_))))
+                {
+                    inspect::dump_proof_tree(infcx, goal);
+                }
                 let (changed, certainty) = match result {
                     Ok(result) => result,
                     Err(NoSolution) => {
@@ -0,0 +1,92 @@
+//! Printing proof trees for `-Zdump-solver-proof-trees`.
+//!
+//! For each root goal this lists the candidates considered while proving it,
+//! together with their `CandidateSource` and result, and recursively does the
+//! same for the nested goals of each candidate.
+
+use std::fmt::Write as _;
+use std::io::Write as _;
+
+use rustc_infer::infer::InferCtxt;
+use rustc_middle::traits::solve::{Certainty, Goal, NoSolution};
+use rustc_middle::ty;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_span::{Span, DUMMY_SP};
+
+use super::{InspectConfig, InspectGoal, ProbeKind, ProofTreeInferCtxtExt, ProofTreeVisitor};
+
+struct ProofTreeDumper {
+    output: String,
+    indent: usize,
+}
+
+impl ProofTreeDumper {
+    fn line(&mut self, args: std::fmt::Arguments<'_>) {
+        let _ = writeln!(self.output, "{:indent$}{args}", "", indent = self.indent * 4);
+    }
+}
+
+fn fmt_result(result: Result<Certainty, NoSolution>) -> String {
+    match result {
+        Ok(Certainty::Yes) => "yes".to_string(),
+        Ok(Certainty::Maybe(cause)) => format!("maybe ({cause:?})"),
+        Err(NoSolution) => "no solution".to_string(),
+    }
+}
+
+impl<'tcx> ProofTreeVisitor<'tcx> for ProofTreeDumper {
+    fn span(&self) -> Span {
+        DUMMY_SP
+    }
+
+    fn config(&self) -> InspectConfig {
+        InspectConfig { max_depth: 32 }
+    }
+
+    fn visit_goal(&mut self, goal: &InspectGoal<'_, 'tcx>) {
+        // Trimmed paths must only be used when emitting a diagnostic.
+        let predicate = with_no_trimmed_paths!(goal.goal().predicate.to_string());
+        self.line(format_args!(
+            "goal {predicate} (source: {:?}): {}",
+            goal.source(),
+            fmt_result(goal.result())
+        ));
+
+        self.indent += 1;
+        for candidate in goal.candidates() {
+            let kind = match candidate.kind() {
+                ProbeKind::TraitCandidate { source, result: _ } => format!("{source:?}"),
+                ProbeKind::Root { result: _ } => "root".to_string(),
+                ProbeKind::TryNormalizeNonRigid { result: _ } => "normalize".to_string(),
+                ProbeKind::OpaqueTypeStorageLookup { result: _ } => {
+                    "opaque type storage".to_string()
+                }
+                ProbeKind::NormalizedSelfTyAssembly => "normalized self type".to_string(),
+                ProbeKind::UnsizeAssembly => "unsize".to_string(),
+                ProbeKind::UpcastProjectionCompatibility => {
+                    "upcast projection compatibility".to_string()
+                }
+                ProbeKind::ShadowedEnvProbing => "shadowed env".to_string(),
+            };
+            self.line(format_args!("candidate {kind}: {}", fmt_result(candidate.result())));
+
+            self.indent += 1;
+            candidate.visit_nested_in_probe(self);
+            self.indent -= 1;
+        }
+        self.indent -= 1;
+    }
+}
+
+/// Computes the proof tree for `goal` and prints it to stderr.
+///
+/// This is used by `-Zdump-solver-proof-trees` once the root goal has been
+/// resolved, and rolls back all inference constraints from evaluating it again.
+pub(crate) fn dump_proof_tree<'tcx>(
+    infcx: &InferCtxt<'tcx>,
+    goal: Goal<'tcx, ty::Predicate<'tcx>>,
+) {
+    let mut dumper = ProofTreeDumper { output: String::new(), indent: 0 };
+    infcx.probe(|_| infcx.visit_proof_tree(goal, &mut dumper));
+    let _ = std::io::stderr().lock().write_all(dumper.output.as_bytes());
+}
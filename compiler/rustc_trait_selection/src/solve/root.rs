@@ -0,0 +1,65 @@
+use rustc_infer::infer::region_constraints::RegionConstraintData;
+use rustc_infer::infer::{InferCtxt, RegionObligation};
+use rustc_infer::traits::solve::{CanonicalResponse, Certainty, Goal, NoSolution};
+use rustc_macros::extension;
+use rustc_middle::ty;
+use rustc_next_trait_solver::solve::{GenerateProofTree, SolverDelegateEvalExt as _};
+
+use super::delegate::SolverDelegate;
+use super::inspect;
+
+/// The result of [`InferCtxtEvalExt::evaluate_root_goal_with_region_constraints`].
+#[derive(Debug)]
+pub struct RootGoalEvaluation<'tcx> {
+    /// The canonical response computed by the trait solver for the goal.
+    pub response: CanonicalResponse<'tcx>,
+    /// The certainty of the goal after applying `response` to the inference context.
+    pub certainty: Certainty,
+    /// The region constraints added while evaluating the goal.
+    pub region_constraints: RegionConstraintData<'tcx>,
+    /// The type-outlives obligations registered while evaluating the goal.
+    pub region_obligations: Vec<RegionObligation<'tcx>>,
+}
+
+#[extension(pub trait InferCtxtEvalExt<'tcx>)]
+impl<'tcx> InferCtxt<'tcx> {
+    /// Evaluates `goal` with the new trait solver from outside of the solver,
+    /// applying the resulting inference constraints to `self`.
+    ///
+    /// Unlike the fulfillment context, this returns the canonical response of the
+    /// goal together with the region constraints and region obligations added while
+    /// evaluating it. Constraints which already existed in `self` are not included.
+    /// This exists for users which need to inspect region constraints directly, like
+    /// rustdoc's synthesis of auto trait impls.
+    fn evaluate_root_goal_with_region_constraints(
+        &self,
+        goal: Goal<'tcx, ty::Predicate<'tcx>>,
+    ) -> Result<RootGoalEvaluation<'tcx>, NoSolution> {
+        assert!(self.next_trait_solver());
+
+        let (constraints_len, member_constraints_len, verifys_len) =
+            self.with_region_constraints(|data| {
+                (data.constraints.len(), data.member_constraints.len(), data.verifys.len())
+            });
+        let region_obligations_len = self.inner.borrow().region_obligations().len();
+
+        let (result, proof_tree) =
+            <&SolverDelegate<'tcx>>::from(self).evaluate_root_goal(goal, GenerateProofTree::Yes);
+        let (_, certainty) = result?;
+        let response = proof_tree.unwrap().evaluation.result?;
+
+        let region_constraints = self.with_region_constraints(|data| RegionConstraintData {
+            constraints: data.constraints[constraints_len..].to_vec(),
+            member_constraints: data.member_constraints[member_constraints_len..].to_vec(),
+            verifys: data.verifys[verifys_len..].to_vec(),
+        });
+        let region_obligations =
+            self.inner.borrow().region_obligations()[region_obligations_len..].to_vec();
+
+        if self.tcx.sess.opts.unstable_opts.dump_solver_proof_trees {
+            inspect::dump_proof_tree(self, goal);
+        }
+
+        Ok(RootGoalEvaluation { response, certainty, region_constraints, region_obligations })
+    }
+}
@@ -8,6 +8,7 @@
     PolyTraitObligation, Selection, SelectionError, SelectionResult,
 };
 use rustc_macros::extension;
+use rustc_middle::ty::TypeVisitableExt;
 use rustc_middle::{bug, span_bug};
 use rustc_span::Span;
 
@@ -21,6 +22,11 @@ fn select_in_new_trait_solver(
     ) -> SelectionResult<'tcx, Selection<'tcx>> {
         assert!(self.next_trait_solver());
 
+        if self.tcx.sess.opts.unstable_opts.dump_solver_proof_trees {
+            let goal = Goal::new(self.tcx, obligation.param_env, obligation.predicate);
+            inspect::dump_proof_tree(self, goal);
+        }
+
         self.visit_proof_tree(
             Goal::new(self.tcx, obligation.param_env, obligation.predicate),
             &mut Select { span: obligation.cause.span },
@@ -124,6 +130,18 @@ fn candidate_should_be_dropped_in_favor_of<'tcx>(
             CandidateSource::BuiltinImpl(BuiltinImplSource::Object { .. }),
         ) => true,
 
+        // Prefer non-global where-bounds over impls, and impls over global where-bounds, like
+        // the old solver does. Global where-bounds such as `Vec<u32>: Clone` do not mention any
+        // generic parameters, so they are no better than the impl they would be proven by.
+        (
+            CandidateSource::Impl(_) | CandidateSource::BuiltinImpl(BuiltinImplSource::Misc),
+            CandidateSource::ParamEnv(idx),
+        ) => !is_global_where_bound(other, idx),
+        (
+            CandidateSource::ParamEnv(idx),
+            CandidateSource::Impl(_) | CandidateSource::BuiltinImpl(BuiltinImplSource::Misc),
+        ) => is_global_where_bound(victim, idx),
+
         // Prefer specializing candidates over specialized candidates.
         (CandidateSource::Impl(victim_def_id), CandidateSource::Impl(other_def_id)) => {
             victim.goal().infcx().tcx.specializes((other_def_id, victim_def_id))
@@ -133,6 +151,11 @@ fn candidate_should_be_dropped_in_favor_of<'tcx>(
     }
 }
 
+fn is_global_where_bound(candidate: &inspect::InspectCandidate<'_, '_>, idx: usize) -> bool {
+    let where_bound = candidate.goal().goal().param_env.caller_bounds()[idx];
+    where_bound.is_global() && !where_bound.has_bound_vars()
+}
+
 fn to_selection<'tcx>(
     span: Span,
     cand: inspect::InspectCandidate<'_, 'tcx>,
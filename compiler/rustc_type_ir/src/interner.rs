@@ -139,6 +139,15 @@ fn with_global_cache<R>(
 
     fn evaluation_is_concurrent(&self) -> bool;
 
+    /// Runs `f` as a `-Zself-profile` activity with the given label, recording
+    /// the path of `def_id` as the event argument.
+    fn with_profiled_activity<R>(
+        self,
+        label: &'static str,
+        def_id: Self::DefId,
+        f: impl FnOnce() -> R,
+    ) -> R;
+
     fn expand_abstract_consts<T: TypeFoldable<Self>>(self, t: T) -> T;
 
     type GenericsOf: GenericsOf<Self>;
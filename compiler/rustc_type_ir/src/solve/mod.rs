@@ -333,4 +333,22 @@ fn unify_with(self, other: MaybeCause) -> MaybeCause {
             ) => MaybeCause::Overflow { suggest_increasing_limit: a || b },
         }
     }
+
+    /// Use this function to merge the causes of multiple candidates which
+    /// may each be used to prove a goal, i.e. when we flounder.
+    ///
+    /// Unlike [`MaybeCause::unify_with`], this only results in overflow if both
+    /// candidates overflowed. If one of them is merely ambiguous, there is a
+    /// finite way to make progress and increasing the recursion limit does not help.
+    pub fn or(self, other: MaybeCause) -> MaybeCause {
+        match (self, other) {
+            (MaybeCause::Ambiguity, MaybeCause::Ambiguity)
+            | (MaybeCause::Ambiguity, MaybeCause::Overflow { .. })
+            | (MaybeCause::Overflow { .. }, MaybeCause::Ambiguity) => MaybeCause::Ambiguity,
+            (
+                MaybeCause::Overflow { suggest_increasing_limit: a },
+                MaybeCause::Overflow { suggest_increasing_limit: b },
+            ) => MaybeCause::Overflow { suggest_increasing_limit: a || b },
+        }
+    }
 }
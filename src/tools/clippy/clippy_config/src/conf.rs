@@ -5,11 +5,11 @@
 use rustc_session::Session;
 use rustc_span::edit_distance::edit_distance;
 use rustc_span::{BytePos, Pos, SourceFile, Span, SyntaxContext};
-use serde::de::{IgnoredAny, IntoDeserializer, MapAccess, Visitor};
+use serde::de::{DeserializeSeed, IgnoredAny, IntoDeserializer, MapAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize};
 use std::fmt::{Debug, Display, Formatter};
 use std::ops::Range;
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 use std::str::FromStr;
 use std::sync::OnceLock;
 use std::{cmp, env, fmt, fs, io};
@@ -237,6 +237,9 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Whether `expect` should be allowed in test functions or `#[cfg(test)]`
     #[lints(expect_used)]
     allow_expect_in_tests: bool = false,
+    /// Whether `indexing_slicing` should be allowed in test functions or `#[cfg(test)]`
+    #[lints(indexing_slicing)]
+    allow_indexing_slicing_in_tests: bool = false,
     /// Whether to allow mixed uninlined format args, e.g. `format!("{} {}", a, foo.bar)`
     #[lints(uninlined_format_args)]
     allow_mixed_uninlined_format_args: bool = true,
@@ -396,6 +399,10 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// Use the Disallowed Names lint instead
     #[conf_deprecated("Please use `disallowed-names` instead", disallowed_names)]
     blacklisted_names: Vec<String> = Vec::new(),
+    /// Additional functions which block the current thread and should not be called from async code,
+    /// written as fully qualified paths.
+    #[lints(blocking_in_async)]
+    blocking_fns: Vec<DisallowedPath> = Vec::new(),
     /// For internal testing only, ignores the current `publish` settings in the Cargo manifest.
     #[lints(cargo_common_metadata)]
     cargo_ignore_publish: bool = false,
@@ -622,6 +629,9 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     /// The byte size a `T` in `Box<T>` can have, below which it triggers the `clippy::unnecessary_box` lint
     #[lints(unnecessary_box_returns)]
     unnecessary_box_size: u64 = 128,
+    /// A list of paths to additional hash-based types whose iteration order is unspecified
+    #[lints(iter_over_hash_type)]
+    unordered_hash_types: Vec<String> = Vec::new(),
     /// Should the fraction of a decimal be linted to include separators.
     #[lints(unreadable_literal)]
     unreadable_literal_lint_fractions: bool = true,
@@ -642,7 +652,8 @@ pub fn get_configuration_metadata() -> Vec<ClippyConfiguration> {
     warn_unsafe_macro_metavars_in_private_macros: bool = false,
 }
 
-/// Search for the configuration file.
+/// Search for the configuration file. A `Cargo.toml` is returned if no `clippy.toml` is found and
+/// the manifest has a `[package.metadata.clippy]` or `[workspace.metadata.clippy]` table.
 ///
 /// # Errors
 ///
@@ -684,8 +695,21 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
             }
         }
 
-        if found_config.is_some() {
-            return Ok((found_config, warnings));
+        let manifest = current.join("Cargo.toml");
+        if let Some(found_config) = found_config {
+            if manifest_has_clippy_config(&manifest) {
+                warnings.push(format!(
+                    "using config file `{}`, the configuration in `{}` will be ignored",
+                    found_config.display(),
+                    manifest.display()
+                ));
+            }
+            return Ok((Some(found_config), warnings));
+        }
+
+        // Fall back to `[package.metadata.clippy]` or `[workspace.metadata.clippy]` in the manifest
+        if manifest_has_clippy_config(&manifest) {
+            return Ok((Some(manifest), warnings));
         }
 
         // If the current directory has no parent, we're done searching.
@@ -695,8 +719,78 @@ pub fn lookup_conf_file() -> io::Result<(Option<PathBuf>, Vec<String>)> {
     }
 }
 
-fn deserialize(file: &SourceFile) -> TryConf {
-    match toml::de::Deserializer::new(file.src.as_ref().unwrap()).deserialize_map(ConfVisitor(file)) {
+/// Tables of a `Cargo.toml` that can hold Clippy's configuration, in order of priority.
+const MANIFEST_CONF_TABLES: [&[&str]; 2] = [&["package", "metadata", "clippy"], &["workspace", "metadata", "clippy"]];
+
+fn manifest_has_clippy_config(manifest: &Path) -> bool {
+    fs::read_to_string(manifest)
+        .ok()
+        .and_then(|contents| toml::from_str::<toml::Value>(&contents).ok())
+        .is_some_and(|manifest| {
+            MANIFEST_CONF_TABLES
+                .iter()
+                .any(|table| table.iter().try_fold(&manifest, |value, &key| value.get(key)).is_some())
+        })
+}
+
+/// Deserializes the table at `path` of a `Cargo.toml` as Clippy's configuration, ignoring every
+/// other key of the manifest.
+struct ManifestTable<'a> {
+    file: &'a SourceFile,
+    path: &'a [&'a str],
+}
+
+impl<'de> DeserializeSeed<'de> for ManifestTable<'_> {
+    type Value = Option<TryConf>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        if self.path.is_empty() {
+            deserializer.deserialize_map(ConfVisitor(self.file)).map(Some)
+        } else {
+            deserializer.deserialize_map(self)
+        }
+    }
+}
+
+impl<'de> Visitor<'de> for ManifestTable<'_> {
+    type Value = Option<TryConf>;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a table")
+    }
+
+    fn visit_map<V: MapAccess<'de>>(self, mut map: V) -> Result<Self::Value, V::Error> {
+        let mut conf = None;
+        while let Some(key) = map.next_key::<String>()? {
+            if key == self.path[0] {
+                conf = map.next_value_seed(ManifestTable {
+                    file: self.file,
+                    path: &self.path[1..],
+                })?;
+            } else {
+                map.next_value::<IgnoredAny>()?;
+            }
+        }
+        Ok(conf)
+    }
+}
+
+fn deserialize(file: &SourceFile, is_manifest: bool) -> TryConf {
+    let src = file.src.as_ref().unwrap();
+    let conf = if is_manifest {
+        MANIFEST_CONF_TABLES
+            .iter()
+            .find_map(|&path| {
+                ManifestTable { file, path }
+                    .deserialize(toml::de::Deserializer::new(src))
+                    .transpose()
+            })
+            .unwrap_or_else(|| Ok(TryConf::default()))
+    } else {
+        toml::de::Deserializer::new(src).deserialize_map(ConfVisitor(file))
+    };
+
+    match conf {
         Ok(mut conf) => {
             extend_vec_if_indicator_present(&mut conf.conf.disallowed_names, DEFAULT_DISALLOWED_NAMES);
             extend_vec_if_indicator_present(&mut conf.conf.allowed_prefixes, DEFAULT_ALLOWED_PREFIXES);
@@ -753,7 +847,7 @@ fn read_inner(sess: &Session, path: &io::Result<(Option<PathBuf>, Vec<String>)>)
             warnings,
         } = match path {
             Ok((Some(path), _)) => match sess.source_map().load_file(path) {
-                Ok(file) => deserialize(&file),
+                Ok(file) => deserialize(&file, path.ends_with("Cargo.toml")),
                 Err(error) => {
                     sess.dcx().err(format!("failed to read `{}`: {error}", path.display()));
                     TryConf::default()
@@ -132,8 +132,9 @@ enum DevCommand {
     #[command(name = "new_lint")]
     /// Create a new lint and run `cargo dev update_lints`
     NewLint {
-        #[arg(short, long, value_parser = ["early", "late"], conflicts_with = "type", default_value = "late")]
-        /// Specify whether the lint runs during the early or late pass
+        #[arg(short, long, value_parser = ["early", "late", "mir"], conflicts_with = "type", default_value = "late")]
+        /// Specify whether the lint runs during the early or late pass, or on the MIR of each function
+        /// (as part of the late pass)
         pass: String,
         #[arg(
             short,
@@ -95,7 +95,7 @@ fn create_project_layout<P: Into<PathBuf>>(
 
         path.push("src");
         fs::create_dir(&path)?;
-        write_file(path.join("main.rs"), get_test_file_contents(lint_name, msrv))?;
+        write_file(path.join("main.rs"), get_test_file_contents(lint_name, "late", msrv))?;
 
         Ok(())
     }
@@ -123,7 +123,7 @@ fn create_project_layout<P: Into<PathBuf>>(
         println!("Generated test directories: `{relative_test_dir}/pass`, `{relative_test_dir}/fail`");
     } else {
         let test_path = format!("tests/ui/{}.rs", lint.name);
-        let test_contents = get_test_file_contents(lint.name, msrv);
+        let test_contents = get_test_file_contents(lint.name, lint.pass, msrv);
         write_file(lint.project_root.join(&test_path), test_contents)?;
 
         println!("Generated test file: `{test_path}`");
@@ -137,20 +137,20 @@ fn add_lint(lint: &LintData<'_>, enable_msrv: bool) -> io::Result<()> {
     let mut lib_rs = fs::read_to_string(path).context("reading")?;
 
     let comment_start = lib_rs.find("// add lints here,").expect("Couldn't find comment");
+    // MIR lints are run from the late pass
+    let lint_pass = if lint.pass == "mir" { "late" } else { lint.pass };
 
     let new_lint = if enable_msrv {
         format!(
             "store.register_{lint_pass}_pass(move |{ctor_arg}| Box::new({module_name}::{camel_name}::new(conf)));\n    ",
-            lint_pass = lint.pass,
-            ctor_arg = if lint.pass == "late" { "_" } else { "" },
+            ctor_arg = if lint_pass == "late" { "_" } else { "" },
             module_name = lint.name,
             camel_name = to_camel_case(lint.name),
         )
     } else {
         format!(
             "store.register_{lint_pass}_pass(|{ctor_arg}| Box::new({module_name}::{camel_name}));\n    ",
-            lint_pass = lint.pass,
-            ctor_arg = if lint.pass == "late" { "_" } else { "" },
+            ctor_arg = if lint_pass == "late" { "_" } else { "" },
             module_name = lint.name,
             camel_name = to_camel_case(lint.name),
         )
@@ -205,7 +205,7 @@ fn parse_manifest(contents: &str) -> Option<String> {
     parse_manifest(&contents).expect("Unable to find package version in `Cargo.toml`")
 }
 
-fn get_test_file_contents(lint_name: &str, msrv: bool) -> String {
+fn get_test_file_contents(lint_name: &str, pass: &str, msrv: bool) -> String {
     let mut test = formatdoc!(
         r#"
         #![warn(clippy::{lint_name})]
@@ -216,6 +216,35 @@ fn main() {{
     "#
     );
 
+    if pass == "mir" {
+        let _ = writedoc!(
+            test,
+            r#"
+
+                // Each branch and loop is lowered to separate basic blocks in MIR, make sure the
+                // lint handles all of them
+                fn branches(x: Option<u32>) -> u32 {{
+                    match x {{
+                        Some(x) if x > 10 => x,
+                        Some(_) => 10,
+                        None => return 0,
+                    }}
+                }}
+
+                fn loops(n: u32) -> u32 {{
+                    let mut sum = 0;
+                    for i in 0..n {{
+                        if i % 2 == 0 {{
+                            continue;
+                        }}
+                        sum += i;
+                    }}
+                    sum
+                }}
+            "#
+        );
+    }
+
     if msrv {
         let _ = writedoc!(
             test,
@@ -255,6 +284,10 @@ fn get_manifest_contents(lint_name: &str, hint: &str) -> String {
 }
 
 fn get_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
+    if lint.pass == "mir" {
+        return get_mir_lint_file_contents(lint, enable_msrv);
+    }
+
     let mut result = String::new();
 
     let (pass_type, pass_lifetimes, pass_import, context_import) = match lint.pass {
@@ -330,6 +363,172 @@ impl {pass_type}{pass_lifetimes} for {name_camel} {{}}
     result
 }
 
+/// MIR lints are late lint passes that fetch the `optimized_mir` of each function in `check_fn`,
+/// along with the skeleton of a dataflow analysis run over it.
+fn get_mir_lint_file_contents(lint: &LintData<'_>, enable_msrv: bool) -> String {
+    let mut result = String::new();
+
+    let lint_name = lint.name;
+    let name_camel = to_camel_case(lint.name);
+    let name_upper = lint.name.to_uppercase();
+
+    result.push_str(&formatdoc!(
+        r#"
+        {msrv_imports}use clippy_utils::fn_has_unsatisfiable_preds;
+        use rustc_hir::def_id::LocalDefId;
+        use rustc_hir::intravisit::FnKind;
+        use rustc_hir::{{Body, FnDecl}};
+        use rustc_index::bit_set::BitSet;
+        use rustc_lint::{{LateContext, LateLintPass{lint_context}}};
+        use rustc_middle::mir::{{self, BasicBlock, CallReturnPlaces, Local, Location, TerminatorEdges}};
+        use rustc_mir_dataflow::{{Analysis, AnalysisDomain}};
+        use rustc_session::{pass_macro};
+        use rustc_span::Span;
+
+    "#,
+        msrv_imports = if enable_msrv {
+            "use clippy_config::msrvs::{self, Msrv};\nuse clippy_config::Conf;\n"
+        } else {
+            ""
+        },
+        lint_context = if enable_msrv { ", LintContext" } else { "" },
+        pass_macro = if enable_msrv { "impl_lint_pass" } else { "declare_lint_pass" },
+    ));
+
+    let _: fmt::Result = writeln!(result, "{}", get_lint_declaration(&name_upper, lint.category));
+
+    if enable_msrv {
+        result.push_str(&formatdoc!(
+            r#"
+            pub struct {name_camel} {{
+                msrv: Msrv,
+            }}
+
+            impl {name_camel} {{
+                pub fn new(conf: &'static Conf) -> Self {{
+                    Self {{ msrv: conf.msrv.clone() }}
+                }}
+            }}
+
+            impl_lint_pass!({name_camel} => [{name_upper}]);
+
+        "#
+        ));
+    } else {
+        result.push_str(&formatdoc!(
+            r#"
+            declare_lint_pass!({name_camel} => [{name_upper}]);
+
+        "#
+        ));
+    }
+
+    result.push_str(&formatdoc!(
+        r#"
+        impl<'tcx> LateLintPass<'tcx> for {name_camel} {{
+            fn check_fn(
+                &mut self,
+                cx: &LateContext<'tcx>,
+                _: FnKind<'tcx>,
+                _: &'tcx FnDecl<'tcx>,
+                _: &'tcx Body<'tcx>,
+                _: Span,
+                def_id: LocalDefId,
+            ) {{
+                // Building MIR for `fn`s with unsatisfiable preds results in ICE.
+                if fn_has_unsatisfiable_preds(cx, def_id.to_def_id()) {{
+                    return;
+                }}
+
+                let mir = cx.tcx.optimized_mir(def_id);
+                let mut state = {name_camel}Analysis
+                    .into_engine(cx.tcx, mir)
+                    .pass_name("{lint_name}")
+                    .iterate_to_fixpoint()
+                    .into_results_cursor(mir);
+
+                for (bb, bb_data) in mir.basic_blocks.iter_enumerated() {{
+                    for (statement_index, statement) in bb_data.statements.iter().enumerate() {{
+                        state.seek_before_primary_effect(Location {{ block: bb, statement_index }});
+                        // TODO: check the statement, `state.get()` is the state right before it
+                        let _: (&mir::Statement<'tcx>, &BitSet<Local>) = (statement, state.get());
+                    }}
+                    state.seek_before_primary_effect(mir.terminator_loc(bb));
+                    // TODO: check the terminator, which is where control flow and calls happen
+                    let _: (&mir::Terminator<'tcx>, &BitSet<Local>) = (bb_data.terminator(), state.get());
+                }}
+            }}
+        {msrv_attr}}}
+
+        /// TODO: describe the state computed by the analysis. The domain is a set of locals, which is
+        /// enough for most analyses, but it can be changed to any type implementing `JoinSemiLattice`.
+        struct {name_camel}Analysis;
+
+        impl<'tcx> AnalysisDomain<'tcx> for {name_camel}Analysis {{
+            type Domain = BitSet<Local>;
+
+            const NAME: &'static str = "{lint_name}";
+
+            fn bottom_value(&self, body: &mir::Body<'tcx>) -> Self::Domain {{
+                BitSet::new_empty(body.local_decls.len())
+            }}
+
+            fn initialize_start_block(&self, _: &mir::Body<'tcx>, _state: &mut Self::Domain) {{
+                // TODO: set the state on entry to the function, e.g. for the arguments
+            }}
+        }}
+
+        impl<'tcx> Analysis<'tcx> for {name_camel}Analysis {{
+            fn apply_statement_effect(
+                &mut self,
+                _state: &mut Self::Domain,
+                _statement: &mir::Statement<'tcx>,
+                _: Location,
+            ) {{
+                // TODO: update the state with the effect of the statement
+            }}
+
+            fn apply_terminator_effect<'mir>(
+                &mut self,
+                _state: &mut Self::Domain,
+                terminator: &'mir mir::Terminator<'tcx>,
+                _: Location,
+            ) -> TerminatorEdges<'mir, 'tcx> {{
+                // TODO: update the state with the effect of the terminator, except for the value
+                // returned by a call, which is handled in `apply_call_return_effect`
+                terminator.edges()
+            }}
+
+            fn apply_call_return_effect(
+                &mut self,
+                _state: &mut Self::Domain,
+                _: BasicBlock,
+                _return_places: CallReturnPlaces<'_, 'tcx>,
+            ) {{
+                // TODO: update the state with the effect of a call returning successfully
+            }}
+        }}
+    "#,
+        msrv_attr = if enable_msrv {
+            "\n    extract_msrv_attr!(LateContext);\n"
+        } else {
+            ""
+        },
+    ));
+
+    if enable_msrv {
+        result.push_str(&formatdoc!(
+            r#"
+
+            // TODO: Add MSRV level to `clippy_config/src/msrvs.rs` if needed.
+            // TODO: Update msrv config comment in `clippy_config/src/conf.rs`
+        "#
+        ));
+    }
+
+    result
+}
+
 fn get_lint_declaration(name_upper: &str, category: &str) -> String {
     let justification_heading = if category == "restriction" {
         "Why restrict this?"
@@ -29,6 +29,8 @@ pub fn run(port: u16, lint: Option<String>) -> ! {
         }
         if let Some(url) = url.take() {
             thread::spawn(move || {
+                // The server keeps running until `cargo dev serve` is exited.
+                #[expect(clippy::zombie_processes)]
                 Command::new(PYTHON)
                     .arg("-m")
                     .arg("http.server")
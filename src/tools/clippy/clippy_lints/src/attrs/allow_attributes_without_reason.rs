@@ -1,7 +1,9 @@
 use super::{Attribute, ALLOW_ATTRIBUTES_WITHOUT_REASON};
 use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::is_from_proc_macro;
+use clippy_utils::source::SpanRangeExt;
 use rustc_ast::{MetaItemKind, NestedMetaItem};
+use rustc_errors::Applicability;
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_span::sym;
@@ -21,14 +23,36 @@ pub(super) fn check<'cx>(cx: &LateContext<'cx>, name: Symbol, items: &[NestedMet
         return;
     }
 
-    #[expect(clippy::collapsible_span_lint_calls, reason = "rust-clippy#7797")]
+    let lints = items
+        .iter()
+        .filter_map(NestedMetaItem::meta_item)
+        .map(|item| {
+            let path = item.path.segments.iter().map(|seg| seg.ident.as_str()).collect::<Vec<_>>();
+            format!("`{}`", path.join("::"))
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+
     span_lint_and_then(
         cx,
         ALLOW_ATTRIBUTES_WITHOUT_REASON,
         attr.span,
         format!("`{}` attribute without specifying a reason", name.as_str()),
         |diag| {
-            diag.help("try adding a reason at the end with `, reason = \"..\"`");
+            // Replacing the last lint rather than inserting after it keeps the suggestion
+            // applicable once when the attribute is linted more than once
+            if let Some(last) = items.last()
+                && let Some(snippet) = last.span().get_source_text(cx)
+            {
+                diag.span_suggestion_verbose(
+                    last.span(),
+                    format!("try adding a reason for {lints} at the end"),
+                    format!("{snippet}, reason = \"...\""),
+                    Applicability::HasPlaceholders,
+                );
+            } else {
+                diag.help(format!("try adding a reason for {lints} at the end with `, reason = \"..\"`"));
+            }
         },
     );
 }
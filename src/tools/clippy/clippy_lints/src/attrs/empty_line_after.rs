@@ -1,9 +1,11 @@
 use super::{EMPTY_LINE_AFTER_DOC_COMMENTS, EMPTY_LINE_AFTER_OUTER_ATTR};
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::span_lint_and_then;
 use clippy_utils::source::{is_present_in_source, without_block_comments, SpanRangeExt};
 use rustc_ast::{AttrKind, AttrStyle};
+use rustc_errors::Applicability;
 use rustc_lint::EarlyContext;
 use rustc_span::Span;
+use std::iter;
 
 /// Check for empty lines after outer attributes.
 ///
@@ -44,9 +46,37 @@ pub(super) fn check(cx: &EarlyContext<'_>, item: &rustc_ast::Item) {
                         ),
                     };
 
-                    span_lint(cx, lint_type, begin_of_attr_to_item, lint_msg);
+                    span_lint_and_then(cx, lint_type, begin_of_attr_to_item, lint_msg, |diag| {
+                        // A doc comment is attached to the item regardless of the empty lines, so
+                        // removing them doesn't change anything. Block comments in between are
+                        // left alone as they may contain empty lines themselves.
+                        if let AttrKind::DocComment(..) = attr.kind
+                            && !snippet.contains("/*")
+                        {
+                            diag.span_suggestion_verbose(
+                                end_of_attr_to_next_attr_or_item,
+                                "if the empty line is unintentional, remove it",
+                                without_empty_lines(&snippet),
+                                Applicability::MachineApplicable,
+                            );
+                        }
+                    });
                 }
             }
         }
     }
 }
+
+/// Removes the empty lines from the text between an attribute and the next attribute or item,
+/// keeping the rest of the attribute's line and the indentation of the next one.
+fn without_empty_lines(snippet: &str) -> String {
+    let lines = snippet.split('\n').collect::<Vec<_>>();
+    let [first, middle @ .., last] = lines.as_slice() else {
+        return snippet.to_owned();
+    };
+    iter::once(*first)
+        .chain(middle.iter().copied().filter(|line| !line.trim().is_empty()))
+        .chain(iter::once(*last))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
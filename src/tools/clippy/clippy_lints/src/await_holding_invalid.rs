@@ -5,7 +5,7 @@
 use rustc_hir::def_id::{DefId, DefIdMap};
 use rustc_lint::{LateContext, LateLintPass};
 use rustc_middle::mir::CoroutineLayout;
-use rustc_middle::ty::TyCtxt;
+use rustc_middle::ty::{self, AdtDef, Ty, TyCtxt};
 use rustc_session::impl_lint_pass;
 use rustc_span::{sym, Span};
 
@@ -202,7 +202,9 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx hir::Expr<'tcx>) {
 impl AwaitHolding {
     fn check_interior_types(&self, cx: &LateContext<'_>, coroutine: &CoroutineLayout<'_>) {
         for (ty_index, ty_cause) in coroutine.field_tys.iter_enumerated() {
-            if let rustc_middle::ty::Adt(adt, _) = ty_cause.ty.kind() {
+            let mut held_adts = Vec::new();
+            collect_held_adts(ty_cause.ty, &mut held_adts);
+            for adt in held_adts {
                 let await_points = || {
                     coroutine
                         .variant_source_info
@@ -268,6 +270,20 @@ fn emit_invalid_type(cx: &LateContext<'_>, span: Span, path: &'static str, reaso
     );
 }
 
+/// Collects the ADTs that a value of type `ty` holds, looking through tuples so that guards
+/// moved into e.g. `(MutexGuard<'_, T>, u32)` are found as well.
+fn collect_held_adts<'tcx>(ty: Ty<'tcx>, adts: &mut Vec<AdtDef<'tcx>>) {
+    match *ty.kind() {
+        ty::Adt(adt, _) => adts.push(adt),
+        ty::Tuple(tys) => {
+            for ty in tys {
+                collect_held_adts(ty, adts);
+            }
+        },
+        _ => {},
+    }
+}
+
 fn is_mutex_guard(cx: &LateContext<'_>, def_id: DefId) -> bool {
     cx.tcx.is_diagnostic_item(sym::MutexGuard, def_id)
         || cx.tcx.is_diagnostic_item(sym::RwLockReadGuard, def_id)
@@ -0,0 +1,118 @@
+use clippy_config::Conf;
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::{def_path_def_ids, paths};
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::DefIdMap;
+use rustc_hir::{Expr, ExprKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::print::with_no_trimmed_paths;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::impl_lint_pass;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for calls to functions that block the current thread inside of `async` functions
+    /// and blocks, such as the free functions in `std::fs`, `std::thread::sleep` and
+    /// `std::net::TcpStream::connect`.
+    ///
+    /// Additional blocking functions can be configured with the `blocking-fns` option.
+    ///
+    /// ### Why is this bad?
+    /// Async runtimes poll many futures on a small number of threads. Blocking one of these
+    /// threads prevents every other future scheduled on it from making progress.
+    ///
+    /// ### Known problems
+    /// Blocking may be acceptable in some contexts, e.g. in an executor that runs a single
+    /// future or when the call is known to return immediately.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// async fn read_config() -> std::io::Result<String> {
+    ///     std::fs::read_to_string("config.toml")
+    /// }
+    /// ```
+    /// Use the async equivalent provided by the runtime instead, or move the call to a thread
+    /// where blocking is allowed (e.g. with `tokio::task::spawn_blocking`).
+    #[clippy::version = "1.82.0"]
+    pub BLOCKING_IN_ASYNC,
+    pedantic,
+    "calling a function that blocks the current thread in an async context"
+}
+
+pub struct BlockingInAsync {
+    /// Maps the blocking functions to the reason they were configured with, if any.
+    blocking_fns: DefIdMap<Option<&'static str>>,
+}
+
+impl BlockingInAsync {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        let mut blocking_fns = DefIdMap::default();
+
+        for module in def_path_def_ids(tcx, &paths::STD_FS) {
+            for child in tcx.module_children(module) {
+                if let Res::Def(DefKind::Fn, def_id) = child.res {
+                    blocking_fns.insert(def_id, None);
+                }
+            }
+        }
+        for path in [
+            &paths::FILE_CREATE[..],
+            &paths::FILE_OPEN,
+            &paths::TCP_STREAM_CONNECT,
+            &paths::THREAD_SLEEP,
+        ] {
+            blocking_fns.extend(def_path_def_ids(tcx, path).map(|def_id| (def_id, None)));
+        }
+        for blocking_fn in &conf.blocking_fns {
+            let path = blocking_fn.path().split("::").collect::<Vec<_>>();
+            blocking_fns.extend(def_path_def_ids(tcx, &path).map(|def_id| (def_id, blocking_fn.reason())));
+        }
+
+        Self { blocking_fns }
+    }
+}
+
+impl_lint_pass!(BlockingInAsync => [BLOCKING_IN_ASYNC]);
+
+impl<'tcx> LateLintPass<'tcx> for BlockingInAsync {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
+        let (def_id, span) = match expr.kind {
+            ExprKind::Path(ref path)
+                if let Res::Def(DefKind::Fn | DefKind::AssocFn, def_id) = cx.qpath_res(path, expr.hir_id) =>
+            {
+                (def_id, expr.span)
+            },
+            ExprKind::MethodCall(name, ..)
+                if let Some(def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id) =>
+            {
+                (def_id, name.ident.span)
+            },
+            _ => return,
+        };
+
+        if let Some(&reason) = self.blocking_fns.get(&def_id)
+            && !in_external_macro(cx.sess(), span)
+            // Closures inside of an async block have their own body, so something like
+            // `spawn_blocking(|| std::fs::read("file"))` isn't linted.
+            && let body_owner = cx.tcx.hir().enclosing_body_owner(expr.hir_id)
+            && cx.tcx.coroutine_is_async(body_owner.to_def_id())
+        {
+            span_lint_and_then(
+                cx,
+                BLOCKING_IN_ASYNC,
+                span,
+                format!(
+                    "call to blocking function `{}` in an async context",
+                    with_no_trimmed_paths!(cx.tcx.def_path_str(def_id))
+                ),
+                |diag| {
+                    if let Some(reason) = reason {
+                        diag.note(reason);
+                    }
+                    diag.help("use a non-blocking alternative or move the call to a thread where blocking is allowed");
+                },
+            );
+        }
+    }
+}
@@ -7,6 +7,7 @@
 use rustc_middle::ty::Visibility;
 use rustc_session::declare_lint_pass;
 use rustc_span::sym;
+use std::iter;
 
 declare_clippy_lint! {
     /// ### What it does
@@ -40,6 +41,7 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
             ItemKind::TyAlias(..)
                 if item.ident.name == sym::Error
                     && is_visible_outside_module(cx, item.owner_id.def_id)
+                    && !is_doc_hidden(cx, item.owner_id.def_id)
                     && let ty = cx.tcx.type_of(item.owner_id).instantiate_identity()
                     && let Some(error_def_id) = cx.tcx.get_diagnostic_item(sym::Error)
                     && implements_trait(cx, ty, error_def_id, &[]) =>
@@ -58,7 +60,8 @@ fn check_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx Item<'tcx>) {
                     && let Some(def_id) = path_res(cx, imp.self_ty).opt_def_id().and_then(DefId::as_local)
                     && let Some(ident) = cx.tcx.opt_item_ident(def_id.to_def_id())
                     && ident.name == sym::Error
-                    && is_visible_outside_module(cx, def_id) =>
+                    && is_visible_outside_module(cx, def_id)
+                    && !is_doc_hidden(cx, def_id) =>
             {
                 span_lint_hir_and_then(
                     cx,
@@ -84,3 +87,10 @@ fn is_visible_outside_module(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
         Visibility::Restricted(mod_def_id) if cx.tcx.parent_module_from_def_id(def_id).to_def_id() == mod_def_id
     )
 }
+
+/// Do not lint `Error`s that are `#[doc(hidden)]`, or in a `#[doc(hidden)]` module, as they aren't
+/// part of the documented API
+fn is_doc_hidden(cx: &LateContext<'_>, def_id: LocalDefId) -> bool {
+    iter::successors(Some(def_id.to_def_id()), |&def_id| cx.tcx.opt_parent(def_id))
+        .any(|def_id| cx.tcx.is_doc_hidden(def_id))
+}
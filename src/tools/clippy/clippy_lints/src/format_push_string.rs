@@ -1,10 +1,17 @@
 use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::macros::root_macro_call;
+use clippy_utils::source::{indent_of, SpanRangeExt};
 use clippy_utils::ty::is_type_lang_item;
-use clippy_utils::{higher, match_def_path, paths};
-use rustc_hir::{BinOpKind, Expr, ExprKind, LangItem, MatchSource};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{higher, is_no_std_crate, match_def_path, path_to_local, paths};
+use rustc_data_structures::fx::FxHashSet;
+use rustc_errors::Applicability;
+use rustc_hir::def::{DefKind, Res};
+use rustc_hir::def_id::LocalModDefId;
+use rustc_hir::{BinOpKind, Expr, ExprKind, HirId, ItemKind, LangItem, MatchSource, Mod, Node, StmtKind, UnOp};
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::declare_lint_pass;
-use rustc_span::sym;
+use rustc_session::impl_lint_pass;
+use rustc_span::{sym, Span};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -38,7 +45,14 @@
     restriction,
     "`format!(..)` appended to existing `String`"
 }
-declare_lint_pass!(FormatPushString => [FORMAT_PUSH_STRING]);
+
+#[derive(Default)]
+pub struct FormatPushString {
+    /// Modules for which the import of `fmt::Write` was already suggested.
+    imported_modules: FxHashSet<LocalModDefId>,
+}
+
+impl_lint_pass!(FormatPushString => [FORMAT_PUSH_STRING]);
 
 fn is_string(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     is_type_lang_item(cx, cx.typeck_results().expr_ty(e).peel_refs(), LangItem::String)
@@ -65,30 +79,113 @@ fn is_format(cx: &LateContext<'_>, e: &Expr<'_>) -> bool {
     }
 }
 
+/// Builds the `write!` call replacing `dst += &format!(..)` or `dst.push_str(&format!(..))`, along
+/// with the import of `fmt::Write` at the top of the enclosing module if it isn't imported there
+/// yet. Only `format!` calls used directly in a statement are handled, as `write!` returns a
+/// `Result` that has to be ignored.
+fn write_sugg<'tcx>(
+    cx: &LateContext<'tcx>,
+    imported_modules: &mut FxHashSet<LocalModDefId>,
+    expr: &Expr<'_>,
+    dst: &Expr<'_>,
+    arg: &'tcx Expr<'tcx>,
+) -> Option<Vec<(Span, String)>> {
+    let Node::Stmt(stmt) = cx.tcx.parent_hir_node(expr.hir_id) else {
+        return None;
+    };
+    let format = arg.peel_borrows();
+    if !matches!(stmt.kind, StmtKind::Semi(_)) || expr.span.from_expansion() || !format.span.from_expansion() {
+        return None;
+    }
+
+    let macro_call = root_macro_call(format.span)?;
+    if !cx.tcx.is_diagnostic_item(sym::format_macro, macro_call.def_id) {
+        return None;
+    }
+
+    // `s += &format!("{s}")` can't be turned into `write!(s, "{s}")`, which borrows `s` twice
+    if let Some(local) = base_local(dst)
+        && is_local_used(cx, format, local)
+    {
+        return None;
+    }
+
+    let module_id = cx.tcx.parent_module(expr.hir_id);
+    let (module, _, _) = cx.tcx.hir().get_module(module_id);
+    let use_span = module.spans.inject_use_span;
+    if use_span.from_expansion() {
+        return None;
+    }
+
+    // `format!("..", args)` -> `"..", args`
+    let format_snip = macro_call.span.get_source_text(cx)?;
+    let (_, args) = format_snip.split_once('!')?;
+    let args = args.trim_start();
+    let args = args.get(1..args.len().checked_sub(1)?)?;
+    let dst_snip = dst.span.get_source_text(cx)?;
+
+    let krate = if is_no_std_crate(cx) { "core" } else { "std" };
+    let use_indent = " ".repeat(indent_of(cx, use_span)?);
+
+    let mut sugg = vec![(expr.span, format!("let _ = write!({dst_snip}, {args})"))];
+    // Every `write!` call of the module is fixed at once, so only the first one adds the import
+    if !imports_fmt_write(cx, module) && imported_modules.insert(module_id) {
+        // `as _` avoids clashing with `io::Write`
+        sugg.push((use_span, format!("use {krate}::fmt::Write as _;\n{use_indent}")));
+    }
+    Some(sugg)
+}
+
+/// Checks if the module has a `use` item importing `fmt::Write` by name.
+fn imports_fmt_write(cx: &LateContext<'_>, module: &Mod<'_>) -> bool {
+    module.item_ids.iter().any(|&id| {
+        if let ItemKind::Use(path, _) = cx.tcx.hir().item(id).kind {
+            path.res.iter().any(|res| {
+                matches!(*res, Res::Def(DefKind::Trait, did) if match_def_path(cx, did, &paths::FMT_WRITE))
+            })
+        } else {
+            false
+        }
+    })
+}
+
+/// Returns the local that `dst` is a place of, e.g. `s` for `s`, `self.s` or `*s`.
+fn base_local(mut dst: &Expr<'_>) -> Option<HirId> {
+    while let ExprKind::Field(base, _) | ExprKind::Index(base, ..) | ExprKind::Unary(UnOp::Deref, base) = dst.kind {
+        dst = base;
+    }
+    path_to_local(dst)
+}
+
 impl<'tcx> LateLintPass<'tcx> for FormatPushString {
     fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
-        let arg = match expr.kind {
-            ExprKind::MethodCall(_, _, [arg], _) => {
+        let (dst, arg) = match expr.kind {
+            ExprKind::MethodCall(_, dst, [arg], _) => {
                 if let Some(fn_def_id) = cx.typeck_results().type_dependent_def_id(expr.hir_id)
                     && match_def_path(cx, fn_def_id, &paths::PUSH_STR)
                 {
-                    arg
+                    (dst, arg)
                 } else {
                     return;
                 }
             },
-            ExprKind::AssignOp(op, left, arg) if op.node == BinOpKind::Add && is_string(cx, left) => arg,
+            ExprKind::AssignOp(op, left, arg) if op.node == BinOpKind::Add && is_string(cx, left) => (left, arg),
             _ => return,
         };
         if is_format(cx, arg) {
-            #[expect(clippy::collapsible_span_lint_calls, reason = "rust-clippy#7797")]
             span_lint_and_then(
                 cx,
                 FORMAT_PUSH_STRING,
                 expr.span,
                 "`format!(..)` appended to existing `String`",
                 |diag| {
-                    diag.help("consider using `write!` to avoid the extra allocation");
+                    let help = "consider using `write!` to avoid the extra allocation";
+                    if let Some(sugg) = write_sugg(cx, &mut self.imported_modules, expr, dst, arg) {
+                        // Writing to a `String` never fails, so ignoring the `Result` is fine
+                        diag.multipart_suggestion_verbose(help, sugg, Applicability::MachineApplicable);
+                    } else {
+                        diag.help(help);
+                    }
                 },
             );
         }
@@ -1,19 +1,25 @@
+use clippy_config::Conf;
 use clippy_utils::diagnostics::span_lint;
 use clippy_utils::higher::ForLoop;
-use clippy_utils::match_any_def_paths;
+use clippy_utils::{def_path_def_ids, match_any_def_paths};
 use clippy_utils::paths::{
     HASHMAP_DRAIN, HASHMAP_ITER, HASHMAP_ITER_MUT, HASHMAP_KEYS, HASHMAP_VALUES, HASHMAP_VALUES_MUT, HASHSET_DRAIN,
     HASHSET_ITER_TY,
 };
 use clippy_utils::ty::is_type_diagnostic_item;
+use rustc_hir::def_id::DefIdSet;
 use rustc_lint::{LateContext, LateLintPass};
-use rustc_session::declare_lint_pass;
+use rustc_middle::ty::TyCtxt;
+use rustc_session::impl_lint_pass;
 use rustc_span::sym;
 
 declare_clippy_lint! {
     /// ### What it does
     /// This is a restriction lint which prevents the use of hash types (i.e., `HashSet` and `HashMap`) in for loops.
     ///
+    /// Additional hash-based types, e.g. from third-party crates, can be linted by listing their paths in the
+    /// `unordered-hash-types` configuration.
+    ///
     /// ### Why restrict this?
     /// Because hash types are unordered, when iterated through such as in a `for` loop, the values are returned in
     /// an undefined order. As a result, on redundant systems this may cause inconsistencies and anomalies.
@@ -40,7 +46,23 @@
     "iterating over unordered hash-based types (`HashMap` and `HashSet`)"
 }
 
-declare_lint_pass!(IterOverHashType => [ITER_OVER_HASH_TYPE]);
+pub struct IterOverHashType {
+    unordered_hash_types: DefIdSet,
+}
+
+impl IterOverHashType {
+    pub fn new(tcx: TyCtxt<'_>, conf: &'static Conf) -> Self {
+        Self {
+            unordered_hash_types: conf
+                .unordered_hash_types
+                .iter()
+                .flat_map(|p| def_path_def_ids(tcx, &p.split("::").collect::<Vec<_>>()))
+                .collect(),
+        }
+    }
+}
+
+impl_lint_pass!(IterOverHashType => [ITER_OVER_HASH_TYPE]);
 
 impl LateLintPass<'_> for IterOverHashType {
     fn check_expr(&mut self, cx: &LateContext<'_>, expr: &'_ rustc_hir::Expr<'_>) {
@@ -65,7 +87,8 @@ fn check_expr(&mut self, cx: &LateContext<'_>, expr: &'_ rustc_hir::Expr<'_>) {
             )
             .is_some()
                 || is_type_diagnostic_item(cx, ty, sym::HashMap)
-                || is_type_diagnostic_item(cx, ty, sym::HashSet))
+                || is_type_diagnostic_item(cx, ty, sym::HashSet)
+                || self.unordered_hash_types.contains(&did))
         {
             span_lint(
                 cx,
@@ -50,6 +50,7 @@
 extern crate rustc_lexer;
 extern crate rustc_lint;
 extern crate rustc_middle;
+extern crate rustc_mir_dataflow;
 extern crate rustc_parse;
 extern crate rustc_resolve;
 extern crate rustc_session;
@@ -82,6 +83,7 @@
 mod async_yields_async;
 mod attrs;
 mod await_holding_invalid;
+mod blocking_in_async;
 mod blocks_in_conditions;
 mod bool_assert_comparison;
 mod bool_to_int_with_if;
@@ -235,6 +237,7 @@
 mod missing_enforced_import_rename;
 mod missing_fields_in_debug;
 mod missing_inline;
+mod missing_must_use_on_builder;
 mod missing_trait_methods;
 mod mixed_read_write_in_expression;
 mod module_style;
@@ -386,6 +389,7 @@
 mod zero_div_zero;
 mod zero_repeat_side_effects;
 mod zero_sized_map_values;
+mod zombie_processes;
 // end lints modules, do not remove this comment, it’s used in `update_lints`
 
 use clippy_config::{get_configuration_metadata, Conf};
@@ -409,6 +413,7 @@ pub fn register_pre_expansion_lints(store: &mut rustc_lint::LintStore, conf: &'s
 #[derive(Default)]
 struct RegistrationGroups {
     all: Vec<LintId>,
+    api: Vec<LintId>,
     cargo: Vec<LintId>,
     complexity: Vec<LintId>,
     correctness: Vec<LintId>,
@@ -426,6 +431,7 @@ impl RegistrationGroups {
     #[rustfmt::skip]
     fn register(self, store: &mut rustc_lint::LintStore) {
         store.register_group(true, "clippy::all", Some("clippy_all"), self.all);
+        store.register_group(true, "clippy::api", None, self.api);
         store.register_group(true, "clippy::cargo", Some("clippy_cargo"), self.cargo);
         store.register_group(true, "clippy::complexity", Some("clippy_complexity"), self.complexity);
         store.register_group(true, "clippy::correctness", Some("clippy_correctness"), self.correctness);
@@ -518,7 +524,11 @@ pub fn explain(name: &str) -> i32 {
     let target = format!("clippy::{}", name.to_ascii_uppercase());
     if let Some(info) = declared_lints::LINTS.iter().find(|info| info.lint.name == target) {
         println!("{}", info.explanation);
-        // Check if the lint has configuration
+        println!("### Lint information\n");
+        println!("- Group: `{}`", info.category_str());
+        println!("- Default level: `{}`", info.lint.default_level.as_str());
+        println!("- Added in: `{}`\n", info.version.unwrap_or("unknown"));
+        // Check if the lint has configuration, this includes `msrv` for lints that respect it
         let mut mdconf = get_configuration_metadata();
         let name = name.to_ascii_lowercase();
         mdconf.retain(|cconf| cconf.lints.contains(&&*name));
@@ -535,6 +545,42 @@ pub fn explain(name: &str) -> i32 {
     }
 }
 
+/// Returns the table of renamed lints as a JSON array of objects with the old and the new name of
+/// each lint.
+pub fn renamed_lints_json() -> String {
+    #[derive(serde::Serialize)]
+    struct RenamedLint {
+        old_name: &'static str,
+        new_name: &'static str,
+        /// The release the rename happened in, if known.
+        version: Option<&'static str>,
+    }
+
+    let renamed: Vec<_> = deprecated_lints::RENAMED
+        .iter()
+        .zip(deprecated_lints::RENAMED_VERSION)
+        .map(|(&(old_name, new_name), &version)| RenamedLint {
+            old_name,
+            new_name,
+            version: (!version.is_empty()).then_some(version),
+        })
+        .collect();
+    serde_json::to_string_pretty(&renamed).unwrap()
+}
+
+/// Prints the table of renamed lints in the given format, used by `--print-renamed-lints`.
+///
+/// The only supported format is `json`, see [`renamed_lints_json`].
+pub fn print_renamed_lints(format: &str) -> i32 {
+    if format != "json" {
+        eprintln!("unknown format for `--print-renamed-lints`: {format}, expected `json`");
+        return 1;
+    }
+
+    println!("{}", renamed_lints_json());
+    0
+}
+
 fn register_categories(store: &mut rustc_lint::LintStore) {
     let mut groups = RegistrationGroups::default();
 
@@ -546,6 +592,17 @@ fn register_categories(store: &mut rustc_lint::LintStore) {
         category.group(&mut groups).push(LintId::of(lint));
     }
 
+    // Unlike the other groups, `clippy::api` gathers lints from several categories that are useful
+    // when auditing the public API of a library
+    groups.api = vec![
+        LintId::of(doc::MISSING_ERRORS_DOC),
+        LintId::of(doc::MISSING_PANICS_DOC),
+        LintId::of(doc::UNNECESSARY_SAFETY_DOC),
+        LintId::of(exhaustive_items::EXHAUSTIVE_STRUCTS),
+        LintId::of(missing_must_use_on_builder::MISSING_MUST_USE_ON_BUILDER),
+        LintId::of(pub_use::PUB_USE),
+    ];
+
     let lints: Vec<&'static Lint> = declared_lints::LINTS.iter().map(|info| *info.lint).collect();
 
     store.register_lints(&lints);
@@ -826,7 +883,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_early_pass(|| Box::new(empty_with_brackets::EmptyWithBrackets));
     store.register_late_pass(|_| Box::new(unnecessary_owned_empty_strings::UnnecessaryOwnedEmptyStrings));
     store.register_early_pass(|| Box::new(pub_use::PubUse));
-    store.register_late_pass(|_| Box::new(format_push_string::FormatPushString));
+    store.register_late_pass(|_| Box::<format_push_string::FormatPushString>::default());
     store.register_late_pass(move |_| Box::new(large_include_file::LargeIncludeFile::new(conf)));
     store.register_late_pass(|_| Box::new(strings::TrimSplitWhitespace));
     store.register_late_pass(|_| Box::new(rc_clone_in_vec_init::RcCloneInVecInit));
@@ -912,7 +969,7 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(move |_| Box::new(manual_hash_one::ManualHashOne::new(conf)));
     store.register_late_pass(|_| Box::new(iter_without_into_iter::IterWithoutIntoIter));
     store.register_late_pass(|_| Box::<pathbuf_init_then_push::PathbufThenPush<'_>>::default());
-    store.register_late_pass(|_| Box::new(iter_over_hash_type::IterOverHashType));
+    store.register_late_pass(move |tcx| Box::new(iter_over_hash_type::IterOverHashType::new(tcx, conf)));
     store.register_late_pass(|_| Box::new(impl_hash_with_borrow_str_and_bytes::ImplHashWithBorrowStrBytes));
     store.register_late_pass(|_| Box::new(repeat_vec_with_capacity::RepeatVecWithCapacity));
     store.register_late_pass(|_| Box::new(uninhabited_references::UninhabitedReferences));
@@ -933,5 +990,8 @@ pub fn register_lints(store: &mut rustc_lint::LintStore, conf: &'static Conf) {
     store.register_late_pass(|_| Box::new(set_contains_or_insert::SetContainsOrInsert));
     store.register_early_pass(|| Box::new(byte_char_slices::ByteCharSlice));
     store.register_early_pass(|| Box::new(cfg_not_test::CfgNotTest));
+    store.register_late_pass(|_| Box::new(zombie_processes::ZombieProcesses));
+    store.register_late_pass(move |tcx| Box::new(blocking_in_async::BlockingInAsync::new(tcx, conf)));
+    store.register_late_pass(|_| Box::new(missing_must_use_on_builder::MissingMustUseOnBuilder));
     // add lints here, do not remove this comment, it's used in `new_lint`
 }
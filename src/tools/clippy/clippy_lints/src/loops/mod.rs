@@ -617,7 +617,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for uses of the `enumerate` method where the index is unused (`_`)
+    /// Checks for uses of the `enumerate` method where the index is unused (`_`, or a binding
+    /// that is never used)
     ///
     /// ### Why is this bad?
     /// The index from `.enumerate()` is immediately dropped.
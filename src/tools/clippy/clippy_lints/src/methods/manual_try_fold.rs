@@ -2,10 +2,11 @@
 use clippy_utils::diagnostics::span_lint_and_sugg;
 use clippy_utils::source::SpanRangeExt;
 use clippy_utils::ty::implements_trait;
-use clippy_utils::{is_from_proc_macro, is_trait_method};
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{is_from_proc_macro, is_trait_method, path_to_local_id, peel_blocks};
 use rustc_errors::Applicability;
 use rustc_hir::def::{DefKind, Res};
-use rustc_hir::{Expr, ExprKind};
+use rustc_hir::{Closure, Expr, ExprKind, PatKind, QPath, TyKind};
 use rustc_lint::{LateContext, LintContext};
 use rustc_middle::lint::in_external_macro;
 use rustc_span::{sym, Span};
@@ -38,8 +39,18 @@ pub(super) fn check<'tcx>(
         let init_snip = rest
             .is_empty()
             .then_some(first.span)
-            .and_then(|span| span.get_source_text(cx))
-            .map_or_else(|| "...".to_owned(), |src| src.to_owned());
+            .and_then(|span| span.get_source_text(cx));
+
+        let (sugg, applicability) = match (init_snip, and_then_closure_sugg(cx, closure, path, &qpath)) {
+            (Some(init_snip), Some(closure_sugg)) => (
+                format!("try_fold({init_snip}, {closure_sugg})"),
+                Applicability::MaybeIncorrect,
+            ),
+            (init_snip, _) => (
+                format!("try_fold({}, {args_snip} ...)", init_snip.as_deref().unwrap_or("...")),
+                Applicability::HasPlaceholders,
+            ),
+        };
 
         span_lint_and_sugg(
             cx,
@@ -47,8 +58,68 @@ pub(super) fn check<'tcx>(
             fold_span,
             "usage of `Iterator::fold` on a type that implements `Try`",
             "use `try_fold` instead",
-            format!("try_fold({init_snip}, {args_snip} ...)",),
-            Applicability::HasPlaceholders,
+            sugg,
+            applicability,
         );
     }
 }
+
+/// If the closure is of the form `|acc, x| acc.and_then(|a| ...)`, returns the closure that can be
+/// passed to `try_fold` instead, i.e. `|a, x| ...`.
+///
+/// `ctor` is the constructor wrapping the initial value. If it has explicit generic arguments, like
+/// `Ok::<i32, ()>`, they're needed to infer the type returned by `try_fold`. They're kept by
+/// rewriting the closure's body, which has to be a call to the same constructor.
+fn and_then_closure_sugg(
+    cx: &LateContext<'_>,
+    closure: &Closure<'_>,
+    ctor: &Expr<'_>,
+    ctor_qpath: &QPath<'_>,
+) -> Option<String> {
+    let body = cx.tcx.hir().body(closure.body);
+    if let [acc_param, item_param] = body.params
+        && let PatKind::Binding(_, acc_id, ..) = acc_param.pat.kind
+        && let ExprKind::MethodCall(seg, receiver, [inner], _) = peel_blocks(body.value).kind
+        && seg.ident.name == sym::and_then
+        && path_to_local_id(receiver, acc_id)
+        && let ExprKind::Closure(inner_closure) = inner.kind
+        && let inner_body = cx.tcx.hir().body(inner_closure.body)
+        && let [inner_param] = inner_body.params
+        && !is_local_used(cx, inner_body.value, acc_id)
+    {
+        let inner_param_snip = inner_param.pat.span.get_source_text(cx)?;
+        let item_param_snip = item_param.pat.span.get_source_text(cx)?;
+        let inner_body_snip = if has_generic_args(ctor_qpath) {
+            // `Ok(sum + i)` -> `Ok::<i32, ()>(sum + i)`
+            let ExprKind::Call(inner_ctor, [inner_arg]) = peel_blocks(inner_body.value).kind else {
+                return None;
+            };
+            let ExprKind::Path(inner_qpath) = inner_ctor.kind else {
+                return None;
+            };
+            if cx.qpath_res(&inner_qpath, inner_ctor.hir_id).opt_def_id()
+                != cx.qpath_res(ctor_qpath, ctor.hir_id).opt_def_id()
+            {
+                return None;
+            }
+            let ctor_snip = ctor.span.get_source_text(cx)?;
+            let arg_snip = inner_arg.span.get_source_text(cx)?;
+            format!("{ctor_snip}({arg_snip})")
+        } else {
+            inner_body.value.span.get_source_text(cx)?.to_string()
+        };
+        Some(format!("|{inner_param_snip}, {item_param_snip}| {inner_body_snip}"))
+    } else {
+        None
+    }
+}
+
+fn has_generic_args(qpath: &QPath<'_>) -> bool {
+    match qpath {
+        QPath::Resolved(_, path) => path.segments.iter().any(|seg| seg.args.is_some()),
+        QPath::TypeRelative(ty, seg) => {
+            seg.args.is_some() || matches!(ty.kind, TyKind::Path(ref qpath) if has_generic_args(qpath))
+        },
+        QPath::LangItem(..) => false,
+    }
+}
@@ -97,6 +97,7 @@
 mod single_char_insert_string;
 mod single_char_push_string;
 mod skip_while_next;
+mod sliced_string_as_bytes;
 mod stable_sort_primitive;
 mod str_split;
 mod str_splitn;
@@ -3442,7 +3443,8 @@
 
 declare_clippy_lint! {
     /// ### What it does
-    /// Checks for `<string_lit>.chars().any(|i| i == c)`.
+    /// Checks for `<string_lit>.chars().any(|i| i == c)`, where the string literal is at most 32
+    /// characters long.
     ///
     /// ### Why is this bad?
     /// It's significantly slower than using a pattern instead, like
@@ -4126,6 +4128,34 @@
     "use of `map` returning the original item"
 }
 
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for string slices immediately followed by `as_bytes`.
+    ///
+    /// ### Why is this bad?
+    /// Slicing the string checks that both ends of the range fall on UTF-8 character boundaries,
+    /// which is unnecessary work when only the bytes are used afterwards.
+    ///
+    /// ### Known problems
+    /// Slicing the string panics if the range doesn't fall on character boundaries, while slicing
+    /// the bytes doesn't. If the code relies on that check, the original form should be kept.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// let s = "Lorem ipsum";
+    /// s[1..5].as_bytes();
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// let s = "Lorem ipsum";
+    /// &s.as_bytes()[1..5];
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub SLICED_STRING_AS_BYTES,
+    perf,
+    "slicing a string and immediately calling as_bytes is less efficient and can lead to panics"
+}
+
 pub struct Methods {
     avoid_breaking_exported_api: bool,
     msrv: Msrv,
@@ -4286,6 +4316,7 @@ pub fn new(conf: &'static Conf, format_args: FormatArgsStorage) -> Self {
     NEEDLESS_CHARACTER_ITERATION,
     MANUAL_INSPECT,
     UNNECESSARY_MIN_OR_MAX,
+    SLICED_STRING_AS_BYTES,
 ]);
 
 /// Extracts a method call name, args, and `Span` of the method name.
@@ -4543,6 +4574,7 @@ fn check_methods<'tcx>(&self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                     if let Some(("as_str", recv, [], as_str_span, _)) = method_call(recv) {
                         redundant_as_str::check(cx, expr, recv, as_str_span, span);
                     }
+                    sliced_string_as_bytes::check(cx, expr, recv);
                 },
                 ("as_mut", []) => useless_asref::check(cx, expr, "as_mut", recv),
                 ("as_ptr", []) => manual_c_str_literals::check_as_ptr(cx, expr, recv, &self.msrv),
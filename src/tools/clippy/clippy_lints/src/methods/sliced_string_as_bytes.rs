@@ -0,0 +1,61 @@
+use clippy_utils::diagnostics::span_lint_and_sugg;
+use clippy_utils::get_parent_expr;
+use clippy_utils::source::snippet_with_applicability;
+use clippy_utils::sugg::Sugg;
+use clippy_utils::ty::is_type_lang_item;
+use rustc_errors::Applicability;
+use rustc_hir::{is_range_literal, BorrowKind, Expr, ExprKind, LangItem, Mutability};
+use rustc_lint::LateContext;
+
+use super::SLICED_STRING_AS_BYTES;
+
+pub(super) fn check(cx: &LateContext<'_>, expr: &Expr<'_>, recv: &Expr<'_>) {
+    if let ExprKind::Index(indexed, index, _) = recv.kind
+        && is_range_literal(index)
+        && let ty = cx.typeck_results().expr_ty(indexed).peel_refs()
+        && (ty.is_str() || is_type_lang_item(cx, ty, LangItem::String))
+    {
+        let mut applicability = Applicability::MachineApplicable;
+        let stringish = Sugg::hir_with_applicability(cx, indexed, "_", &mut applicability).maybe_par();
+        let range = snippet_with_applicability(cx, index.span, "_", &mut applicability);
+        let sugg = Sugg::NonParen(format!("{stringish}.as_bytes()[{range}]").into()).addr();
+
+        let (span, sugg) = match get_parent_expr(cx, expr) {
+            // `&s[a..b].as_bytes()`: the suggestion already borrows the bytes, so replace the outer
+            // reference too. This changes the type from `&&[u8]` to `&[u8]`.
+            Some(Expr {
+                kind: ExprKind::AddrOf(BorrowKind::Ref, Mutability::Not, _),
+                span,
+                ..
+            }) => {
+                applicability = Applicability::MaybeIncorrect;
+                (*span, sugg)
+            },
+            // `s[a..b].as_bytes().len()`: the bytes are used as the base of another expression, which
+            // needs parentheses around the suggestion.
+            Some(parent) if is_base_of(parent, expr) => {
+                applicability = Applicability::MaybeIncorrect;
+                (expr.span, sugg.maybe_par())
+            },
+            _ => (expr.span, sugg),
+        };
+
+        span_lint_and_sugg(
+            cx,
+            SLICED_STRING_AS_BYTES,
+            span,
+            "calling `as_bytes` after slicing a string",
+            "try",
+            sugg.to_string(),
+            applicability,
+        );
+    }
+}
+
+fn is_base_of(parent: &Expr<'_>, expr: &Expr<'_>) -> bool {
+    matches!(
+        parent.kind,
+        ExprKind::MethodCall(_, base, ..) | ExprKind::Field(base, _) | ExprKind::Index(base, ..)
+            if base.hir_id == expr.hir_id
+    )
+}
@@ -11,6 +11,9 @@
 
 use super::STRING_LIT_CHARS_ANY;
 
+/// The maximum number of chars in the string literal for the lint to suggest a `matches!`.
+const MAX_LIT_CHARS: usize = 32;
+
 pub(super) fn check<'tcx>(
     cx: &LateContext<'tcx>,
     expr: &'tcx Expr<'tcx>,
@@ -24,6 +27,8 @@ pub(super) fn check<'tcx>(
         && let PatKind::Binding(_, arg, _, _) = param.pat.kind
         && let ExprKind::Lit(lit_kind) = recv.kind
         && let LitKind::Str(val, _) = lit_kind.node
+        // The suggestion would be invalid for an empty string and unreadable for a long one
+        && (1..=MAX_LIT_CHARS).contains(&val.as_str().chars().count())
         && let ExprKind::Binary(kind, lhs, rhs) = body.kind
         && let BinOpKind::Eq = kind.node
         && let Some(lhs_path) = path_to_local(lhs)
@@ -0,0 +1,120 @@
+use clippy_utils::diagnostics::span_lint_and_help;
+use clippy_utils::return_ty;
+use clippy_utils::ty::is_must_use_ty;
+use rustc_hir::def_id::DefId;
+use rustc_hir::{ImplItem, ImplItemKind};
+use rustc_lint::{LateContext, LateLintPass, LintContext};
+use rustc_middle::lint::in_external_macro;
+use rustc_middle::ty::{self, AssocKind, Ty};
+use rustc_session::declare_lint_pass;
+use rustc_span::sym;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Checks for public methods of builder types which return the builder, either as `Self` or as
+    /// `&mut Self`, and don't have the `#[must_use]` attribute. A type is considered to be a builder
+    /// if it has an inherent `build` method.
+    ///
+    /// ### Why restrict this?
+    /// A builder which is configured but never built is most likely a bug, e.g. when the result of
+    /// a setter taking `self` is dropped instead of being assigned back. Marking the methods
+    /// returning the builder with `#[must_use]` catches this at the call site.
+    ///
+    /// ### Known problems
+    /// Methods returning `&mut Self` are commonly called on a local builder without using the
+    /// returned reference. Once marked with `#[must_use]`, such calls have to be written as
+    /// `let _ = builder.method();`.
+    ///
+    /// ### Example
+    /// ```no_run
+    /// pub struct Request;
+    ///
+    /// pub struct RequestBuilder {
+    ///     retries: u32,
+    /// }
+    ///
+    /// impl RequestBuilder {
+    ///     pub fn retries(mut self, retries: u32) -> Self {
+    ///         self.retries = retries;
+    ///         self
+    ///     }
+    ///
+    ///     pub fn build(self) -> Request {
+    ///         Request
+    ///     }
+    /// }
+    /// ```
+    /// Use instead:
+    /// ```no_run
+    /// pub struct Request;
+    ///
+    /// pub struct RequestBuilder {
+    ///     retries: u32,
+    /// }
+    ///
+    /// impl RequestBuilder {
+    ///     #[must_use]
+    ///     pub fn retries(mut self, retries: u32) -> Self {
+    ///         self.retries = retries;
+    ///         self
+    ///     }
+    ///
+    ///     pub fn build(self) -> Request {
+    ///         Request
+    ///     }
+    /// }
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub MISSING_MUST_USE_ON_BUILDER,
+    restriction,
+    "missing `#[must_use]` annotation on a builder method returning the builder"
+}
+
+declare_lint_pass!(MissingMustUseOnBuilder => [MISSING_MUST_USE_ON_BUILDER]);
+
+impl<'tcx> LateLintPass<'tcx> for MissingMustUseOnBuilder {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &'tcx ImplItem<'tcx>) {
+        if let ImplItemKind::Fn(ref sig, _) = item.kind
+            // Constructors aren't builder methods.
+            && sig.decl.implicit_self.has_implicit_self()
+            && !in_external_macro(cx.sess(), item.span)
+            && cx.effective_visibilities.is_exported(item.owner_id.def_id)
+            && let Some(impl_def) = cx.tcx.impl_of_method(item.owner_id.to_def_id())
+            // For trait impls, the `#[must_use]` should be put on the trait definition instead.
+            && cx.tcx.trait_id_of_impl(impl_def).is_none()
+            && !cx.tcx.hir().attrs(item.hir_id()).iter().any(|attr| attr.has_name(sym::must_use))
+            && let self_ty = cx.tcx.type_of(impl_def).instantiate_identity()
+            && let ty::Adt(adt, _) = self_ty.kind()
+            && returns_builder(cx, return_ty(cx, item.owner_id), self_ty)
+            && is_builder(cx, adt.did())
+        {
+            span_lint_and_help(
+                cx,
+                MISSING_MUST_USE_ON_BUILDER,
+                item.span,
+                "missing `#[must_use]` attribute on a builder method",
+                None,
+                "consider adding the `#[must_use]` attribute to the method",
+            );
+        }
+    }
+}
+
+/// Checks if `ret_ty` is `Self`, unless `Self` is already `#[must_use]`, or `&mut Self`. The
+/// attribute on the type doesn't apply to references to it.
+fn returns_builder<'tcx>(cx: &LateContext<'tcx>, ret_ty: Ty<'tcx>, self_ty: Ty<'tcx>) -> bool {
+    match *ret_ty.kind() {
+        ty::Ref(_, ty, ty::Mutability::Mut) => ty == self_ty,
+        _ => ret_ty == self_ty && !is_must_use_ty(cx, self_ty),
+    }
+}
+
+/// Checks if the type has an inherent `build` method.
+fn is_builder(cx: &LateContext<'_>, adt_did: DefId) -> bool {
+    cx.tcx.inherent_impls(adt_did).into_iter().flatten().any(|&impl_did| {
+        cx.tcx
+            .associated_items(impl_did)
+            .filter_by_name_unhygienic(sym!(build))
+            .any(|item| item.kind == AssocKind::Fn)
+    })
+}
@@ -152,7 +152,9 @@ fn check_generics(&mut self, cx: &LateContext<'_>, generics: &Generics<'_>) {
                             generics.span_for_bound_removal(sized_bound.predicate_pos, sized_bound.bound_pos),
                             "change the bounds that require `Sized`, or remove the `?Sized` bound",
                             "",
-                            Applicability::MaybeIncorrect,
+                            // the parameter is already `Sized`, so removing `?Sized` doesn't change
+                            // what the bounds mean
+                            Applicability::MachineApplicable,
                         );
                     },
                 );
@@ -1,6 +1,6 @@
 use std::ops::ControlFlow;
 
-use clippy_utils::diagnostics::span_lint;
+use clippy_utils::diagnostics::span_lint_and_help;
 use clippy_utils::ty::is_type_diagnostic_item;
 use clippy_utils::visitors::for_each_expr;
 use clippy_utils::{higher, peel_hir_expr_while, SpanlessEq};
@@ -17,6 +17,7 @@
     ///
     /// ### Why is this bad?
     /// Using just `insert` and checking the returned `bool` is more efficient.
+    /// For maps, see `map_entry`, which covers `contains_key` followed by `insert`.
     ///
     /// ### Known problems
     /// In case the value that wants to be inserted is borrowed and also expensive or impossible
@@ -58,14 +59,16 @@ fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'_>) {
                 then: then_expr,
                 ..
             }) = higher::If::hir(expr)
-            && let Some((contains_expr, sym)) = try_parse_op_call(cx, cond_expr, sym!(contains))//try_parse_contains(cx, cond_expr)
+            && let Some((contains_expr, sym)) = try_parse_op_call(cx, cond_expr, sym!(contains))
             && let Some(insert_expr) = find_insert_calls(cx, &contains_expr, then_expr)
         {
-            span_lint(
+            span_lint_and_help(
                 cx,
                 SET_CONTAINS_OR_INSERT,
                 vec![contains_expr.span, insert_expr.span],
                 format!("usage of `{sym}::insert` after `{sym}::contains`"),
+                None,
+                "use the `bool` returned by `insert` instead of calling `contains` first",
             );
         }
     }
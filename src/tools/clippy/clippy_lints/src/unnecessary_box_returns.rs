@@ -1,12 +1,18 @@
 use clippy_config::Conf;
-use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::diagnostics::span_lint_hir_and_then;
+use clippy_utils::path_def_id;
 use clippy_utils::ty::approx_ty_size;
+use clippy_utils::visitors::find_all_ret_expressions;
+use rustc_data_structures::fx::{FxHashSet, FxIndexMap};
 use rustc_errors::Applicability;
 use rustc_hir::def_id::LocalDefId;
-use rustc_hir::{FnDecl, FnRetTy, ImplItemKind, Item, ItemKind, Node, TraitItem, TraitItemKind};
+use rustc_hir::{
+    BodyId, Expr, ExprKind, FnDecl, FnRetTy, ImplItemKind, Item, ItemKind, Node, TraitFn, TraitItem, TraitItemKind,
+};
 use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::ty;
 use rustc_session::impl_lint_pass;
-use rustc_span::Symbol;
+use rustc_span::{sym, Span, Symbol};
 
 declare_clippy_lint! {
     /// ### What it does
@@ -43,6 +49,12 @@
 pub struct UnnecessaryBoxReturns {
     avoid_breaking_exported_api: bool,
     maximum_size: u64,
+    /// Functions that would be linted, along with the span of their return type and the boxed type.
+    /// They are only linted at the end of the crate, once we know they aren't used as a `fn` pointer.
+    boxed_returns: FxIndexMap<LocalDefId, (Span, String)>,
+    /// Functions used as something other than the callee of a call, e.g. passed as a `fn` pointer,
+    /// which would require a specific signature.
+    used_fn_def_ids: FxHashSet<LocalDefId>,
 }
 
 impl_lint_pass!(UnnecessaryBoxReturns => [UNNECESSARY_BOX_RETURNS]);
@@ -52,10 +64,19 @@ pub fn new(conf: &'static Conf) -> Self {
         Self {
             avoid_breaking_exported_api: conf.avoid_breaking_exported_api,
             maximum_size: conf.unnecessary_box_size,
+            boxed_returns: FxIndexMap::default(),
+            used_fn_def_ids: FxHashSet::default(),
         }
     }
 
-    fn check_fn_item(&mut self, cx: &LateContext<'_>, decl: &FnDecl<'_>, def_id: LocalDefId, name: Symbol) {
+    fn check_fn_item(
+        &mut self,
+        cx: &LateContext<'_>,
+        decl: &FnDecl<'_>,
+        def_id: LocalDefId,
+        name: Symbol,
+        body: Option<BodyId>,
+    ) {
         // we don't want to tell someone to break an exported function if they ask us not to
         if self.avoid_breaking_exported_api && cx.effective_visibilities.is_exported(def_id) {
             return;
@@ -79,40 +100,44 @@ fn check_fn_item(&mut self, cx: &LateContext<'_>, decl: &FnDecl<'_>, def_id: Loc
             return;
         };
 
+        // Only lint if every returned box is created in this function, e.g. don't lint if the box
+        // comes from a field or another function.
+        if let Some(body) = body
+            && !find_all_ret_expressions(cx, cx.tcx.hir().body(body).value, |ret_expr| {
+                if let ExprKind::Call(func, [_]) = ret_expr.kind
+                    && let Some(func_def_id) = path_def_id(cx, func)
+                {
+                    cx.tcx.is_diagnostic_item(sym::box_new, func_def_id)
+                } else {
+                    false
+                }
+            })
+        {
+            return;
+        }
+
         // It's sometimes useful to return Box<T> if T is unsized, so don't lint those.
         // Also, don't lint if we know that T is very large, in which case returning
         // a Box<T> may be beneficial.
         if boxed_ty.is_sized(cx.tcx, cx.param_env) && approx_ty_size(cx, boxed_ty) <= self.maximum_size {
-            span_lint_and_then(
-                cx,
-                UNNECESSARY_BOX_RETURNS,
-                return_ty_hir.span,
-                format!("boxed return of the sized type `{boxed_ty}`"),
-                |diagnostic| {
-                    diagnostic.span_suggestion(
-                        return_ty_hir.span,
-                        "try",
-                        boxed_ty.to_string(),
-                        // the return value and function callers also needs to
-                        // be changed, so this can't be MachineApplicable
-                        Applicability::Unspecified,
-                    );
-                    diagnostic.help("changing this also requires a change to the return expressions in this function");
-                },
-            );
+            self.boxed_returns.insert(def_id, (return_ty_hir.span, boxed_ty.to_string()));
         }
     }
 }
 
-impl LateLintPass<'_> for UnnecessaryBoxReturns {
-    fn check_trait_item(&mut self, cx: &LateContext<'_>, item: &TraitItem<'_>) {
-        let TraitItemKind::Fn(signature, _) = &item.kind else {
+impl<'tcx> LateLintPass<'tcx> for UnnecessaryBoxReturns {
+    fn check_trait_item(&mut self, cx: &LateContext<'tcx>, item: &TraitItem<'tcx>) {
+        let TraitItemKind::Fn(signature, trait_fn) = &item.kind else {
             return;
         };
-        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name);
+        let body = match trait_fn {
+            TraitFn::Provided(body) => Some(*body),
+            TraitFn::Required(_) => None,
+        };
+        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name, body);
     }
 
-    fn check_impl_item(&mut self, cx: &LateContext<'_>, item: &rustc_hir::ImplItem<'_>) {
+    fn check_impl_item(&mut self, cx: &LateContext<'tcx>, item: &rustc_hir::ImplItem<'tcx>) {
         // Ignore implementations of traits, because the lint should be on the
         // trait, not on the implementation of it.
         let Node::Item(parent) = cx.tcx.parent_hir_node(item.hir_id()) else {
@@ -123,16 +148,61 @@ fn check_impl_item(&mut self, cx: &LateContext<'_>, item: &rustc_hir::ImplItem<'
             return;
         }
 
-        let ImplItemKind::Fn(signature, ..) = &item.kind else {
+        let ImplItemKind::Fn(signature, body) = &item.kind else {
             return;
         };
-        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name);
+        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name, Some(*body));
     }
 
-    fn check_item(&mut self, cx: &LateContext<'_>, item: &Item<'_>) {
-        let ItemKind::Fn(signature, ..) = &item.kind else {
+    fn check_item(&mut self, cx: &LateContext<'tcx>, item: &Item<'tcx>) {
+        let ItemKind::Fn(signature, _, body) = &item.kind else {
             return;
         };
-        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name);
+        self.check_fn_item(cx, signature.decl, item.owner_id.def_id, item.ident.name, Some(*body));
+    }
+
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        // Changing the signature of a function used as a `fn` pointer (or unified with another
+        // function) may not be possible, only calling it is fine.
+        if let ExprKind::Path(..) = expr.kind
+            && let ty::FnDef(def_id, _) = cx.typeck_results().expr_ty(expr).kind()
+            && let Some(def_id) = def_id.as_local()
+        {
+            if let Node::Expr(e) = cx.tcx.parent_hir_node(expr.hir_id)
+                && let ExprKind::Call(call, _) = e.kind
+                && call.hir_id == expr.hir_id
+            {
+                return;
+            }
+
+            self.used_fn_def_ids.insert(def_id);
+        }
+    }
+
+    fn check_crate_post(&mut self, cx: &LateContext<'tcx>) {
+        for (def_id, (span, boxed_ty)) in &self.boxed_returns {
+            if self.used_fn_def_ids.contains(def_id) {
+                continue;
+            }
+
+            span_lint_hir_and_then(
+                cx,
+                UNNECESSARY_BOX_RETURNS,
+                cx.tcx.local_def_id_to_hir_id(*def_id),
+                *span,
+                format!("boxed return of the sized type `{boxed_ty}`"),
+                |diagnostic| {
+                    diagnostic.span_suggestion(
+                        *span,
+                        "try",
+                        boxed_ty,
+                        // the return value and function callers also needs to
+                        // be changed, so this can't be MachineApplicable
+                        Applicability::Unspecified,
+                    );
+                    diagnostic.help("changing this also requires a change to the return expressions in this function");
+                },
+            );
+        }
     }
 }
@@ -0,0 +1,256 @@
+use clippy_utils::diagnostics::span_lint_and_then;
+use clippy_utils::visitors::is_local_used;
+use clippy_utils::{get_enclosing_block, path_to_local_id};
+use rustc_hir::intravisit::{walk_block, walk_expr, walk_local, Visitor};
+use rustc_hir::{Expr, ExprKind, HirId, LetStmt, MatchSource, Node, PatKind, StmtKind};
+use rustc_lint::{LateContext, LateLintPass};
+use rustc_middle::lint::in_external_macro;
+use rustc_session::declare_lint_pass;
+use rustc_span::{sym, Span};
+use std::ops::ControlFlow;
+
+declare_clippy_lint! {
+    /// ### What it does
+    /// Looks for code that spawns a process but never calls `wait()` on the child.
+    ///
+    /// ### Why is this bad?
+    /// As explained in the [standard library documentation], calling `wait()` is necessary on Unix
+    /// platforms to properly release all OS resources associated with the process. Not doing so
+    /// will effectively leak process IDs and/or other limited global resources, which can
+    /// eventually lead to resource exhaustion. Such processes are called "zombie processes".
+    ///
+    /// [standard library documentation]: https://doc.rust-lang.org/stable/std/process/struct.Child.html#warning
+    ///
+    /// ### Known problems
+    /// The child is assumed to be waited on as soon as it is passed to a function, moved into
+    /// a closure or otherwise escapes the function that spawned it.
+    ///
+    /// ### Example
+    /// ```rust
+    /// use std::process::Command;
+    ///
+    /// let _child = Command::new("ls").spawn().expect("failed to execute child");
+    /// ```
+    /// Use instead:
+    /// ```rust
+    /// use std::process::Command;
+    ///
+    /// let mut child = Command::new("ls").spawn().expect("failed to execute child");
+    /// child.wait().expect("failed to wait on child");
+    /// ```
+    #[clippy::version = "1.82.0"]
+    pub ZOMBIE_PROCESSES,
+    suspicious,
+    "not waiting on a spawned child process"
+}
+
+declare_lint_pass!(ZombieProcesses => [ZOMBIE_PROCESSES]);
+
+impl<'tcx> LateLintPass<'tcx> for ZombieProcesses {
+    fn check_expr(&mut self, cx: &LateContext<'tcx>, expr: &'tcx Expr<'tcx>) {
+        if let ExprKind::Call(..) | ExprKind::MethodCall(..) | ExprKind::Match(.., MatchSource::TryDesugar(_)) =
+            expr.kind
+            && let Some(child_adt) = cx.typeck_results().expr_ty(expr).ty_adt_def()
+            && cx.tcx.is_diagnostic_item(sym::Child, child_adt.did())
+            && !in_external_macro(cx.sess(), expr.span)
+        {
+            match cx.tcx.parent_hir_node(expr.hir_id) {
+                Node::LetStmt(local)
+                    if let PatKind::Binding(_, local_id, ..) = local.pat.kind
+                        && let Some(enclosing_block) = get_enclosing_block(cx, expr.hir_id) =>
+                {
+                    let mut vis = WaitFinder {
+                        cx,
+                        local_id,
+                        state: VisitorState::WalkUpToLocal,
+                        early_return: None,
+                        missing_wait_branch: None,
+                    };
+
+                    let cause = match (walk_block(&mut vis, enclosing_block), vis.missing_wait_branch) {
+                        (ControlFlow::Break(MaybeWait(wait_span)), _) => match vis.early_return {
+                            Some(return_span) => Cause::EarlyReturn { wait_span, return_span },
+                            None => return,
+                        },
+                        (ControlFlow::Continue(()), None) => Cause::NeverWait,
+                        (ControlFlow::Continue(()), Some(missing_wait_branch)) => {
+                            Cause::MissingWait(missing_wait_branch)
+                        },
+                    };
+
+                    emit_lint(cx, expr.span, cause);
+                },
+                // `let _ = Command::new("..").spawn().unwrap();` and
+                // `Command::new("..").spawn().unwrap();` drop the child right away.
+                Node::LetStmt(local) if matches!(local.pat.kind, PatKind::Wild) => {
+                    emit_lint(cx, expr.span, Cause::NeverWait);
+                },
+                Node::Stmt(stmt) if matches!(stmt.kind, StmtKind::Semi(_)) => {
+                    emit_lint(cx, expr.span, Cause::NeverWait);
+                },
+                _ => {},
+            }
+        }
+    }
+}
+
+/// Returned by the visitor once a possible `wait()` call on the child has been found, or once the
+/// child escapes the analysis (e.g. by being passed to a function).
+struct MaybeWait(Span);
+
+#[derive(Clone, Copy)]
+enum MissingWaitBranch {
+    /// `if cond { child.wait(); }` without an `else` branch.
+    MissingElse { if_span: Span, wait_span: Span },
+    /// An `if`/`else` where only one of the branches waits on the child.
+    MissingWaitInBranch { branch_span: Span, wait_span: Span },
+}
+
+enum Cause {
+    /// No call to `wait()` was found.
+    NeverWait,
+    /// `wait()` is called, but an early return before it skips the call.
+    EarlyReturn { wait_span: Span, return_span: Span },
+    /// `wait()` is only called in some branches.
+    MissingWait(MissingWaitBranch),
+}
+
+#[derive(PartialEq)]
+enum VisitorState {
+    /// Walking the statements that come before the `let` binding of the child.
+    WalkUpToLocal,
+    /// The child has been bound, look for uses of it.
+    LookingForWait,
+}
+
+/// Visits the block that binds the child process and finds the first use of it, keeping track of
+/// early returns and branches that don't wait on the child along the way.
+struct WaitFinder<'a, 'tcx> {
+    cx: &'a LateContext<'tcx>,
+    local_id: HirId,
+    state: VisitorState,
+    early_return: Option<Span>,
+    missing_wait_branch: Option<MissingWaitBranch>,
+}
+
+impl<'tcx> Visitor<'tcx> for WaitFinder<'_, 'tcx> {
+    type Result = ControlFlow<MaybeWait>;
+
+    fn visit_local(&mut self, l: &'tcx LetStmt<'tcx>) -> Self::Result {
+        if self.state == VisitorState::WalkUpToLocal
+            && let PatKind::Binding(_, pat_id, ..) = l.pat.kind
+            && self.local_id == pat_id
+        {
+            self.state = VisitorState::LookingForWait;
+            return ControlFlow::Continue(());
+        }
+
+        walk_local(self, l)
+    }
+
+    fn visit_expr(&mut self, ex: &'tcx Expr<'tcx>) -> Self::Result {
+        if self.state != VisitorState::LookingForWait {
+            return walk_expr(self, ex);
+        }
+
+        match ex.kind {
+            ExprKind::Ret(value) => {
+                // `return child;` is a use of the child rather than an early return.
+                if let Some(value) = value {
+                    walk_expr(self, value)?;
+                }
+                self.early_return.get_or_insert(ex.span);
+                return ControlFlow::Continue(());
+            },
+            ExprKind::Closure(closure) => {
+                // Returns inside of a closure don't return from the enclosing function, so only
+                // look for uses of the child in there.
+                let body = self.cx.tcx.hir().body(closure.body);
+                if is_local_used(self.cx, body.value, self.local_id) {
+                    return ControlFlow::Break(MaybeWait(ex.span));
+                }
+                return ControlFlow::Continue(());
+            },
+            ExprKind::If(cond, then, None) => {
+                walk_expr(self, cond)?;
+
+                if let ControlFlow::Break(MaybeWait(wait_span)) = walk_expr(self, then) {
+                    self.missing_wait_branch
+                        .get_or_insert(MissingWaitBranch::MissingElse { if_span: ex.span, wait_span });
+                }
+
+                return ControlFlow::Continue(());
+            },
+            ExprKind::If(cond, then, Some(else_)) => {
+                walk_expr(self, cond)?;
+
+                match (walk_expr(self, then), walk_expr(self, else_)) {
+                    (ControlFlow::Continue(()), ControlFlow::Continue(())) => {},
+                    // `wait()` (or some other use) in both branches
+                    (ControlFlow::Break(wait), ControlFlow::Break(_)) => return ControlFlow::Break(wait),
+                    (ControlFlow::Break(MaybeWait(wait_span)), ControlFlow::Continue(())) => {
+                        self.missing_wait_branch.get_or_insert(MissingWaitBranch::MissingWaitInBranch {
+                            branch_span: else_.span,
+                            wait_span,
+                        });
+                    },
+                    (ControlFlow::Continue(()), ControlFlow::Break(MaybeWait(wait_span))) => {
+                        self.missing_wait_branch.get_or_insert(MissingWaitBranch::MissingWaitInBranch {
+                            branch_span: then.span,
+                            wait_span,
+                        });
+                    },
+                }
+
+                return ControlFlow::Continue(());
+            },
+            _ if path_to_local_id(ex, self.local_id) => {
+                return match self.cx.tcx.parent_hir_node(ex.hir_id) {
+                    // `child.kill()` and `child.id()` don't reap the process, keep looking.
+                    Node::Expr(parent)
+                        if let ExprKind::MethodCall(segment, receiver, ..) = parent.kind
+                            && receiver.hir_id == ex.hir_id
+                            && matches!(segment.ident.as_str(), "kill" | "id") =>
+                    {
+                        ControlFlow::Continue(())
+                    },
+                    // Any other use, including `child.wait()`, is assumed to wait on the child.
+                    _ => ControlFlow::Break(MaybeWait(ex.span)),
+                };
+            },
+            _ => {},
+        }
+
+        walk_expr(self, ex)
+    }
+}
+
+fn emit_lint(cx: &LateContext<'_>, span: Span, cause: Cause) {
+    let message = match cause {
+        Cause::NeverWait => "spawned process is never `wait()`ed on",
+        Cause::EarlyReturn { .. } | Cause::MissingWait(_) => "spawned process is not `wait()`ed on in all code paths",
+    };
+
+    span_lint_and_then(cx, ZOMBIE_PROCESSES, span, message, |diag| {
+        match cause {
+            Cause::NeverWait => {
+                diag.note("consider calling `.wait()`");
+            },
+            Cause::EarlyReturn { wait_span, return_span } => {
+                diag.span_note(return_span, "no `wait()` call exists on the code path to this early return");
+                diag.span_note(wait_span, "`wait()` call exists, but it is unreachable due to the early return");
+            },
+            Cause::MissingWait(MissingWaitBranch::MissingElse { if_span, wait_span }) => {
+                diag.span_note(wait_span, "`wait()` called here");
+                diag.span_note(if_span, "but not in the implicit `else` branch of this `if`");
+            },
+            Cause::MissingWait(MissingWaitBranch::MissingWaitInBranch { branch_span, wait_span }) => {
+                diag.span_note(wait_span, "`wait()` called here");
+                diag.span_note(branch_span, "but not in this branch");
+            },
+        }
+
+        diag.note("not doing so might leave behind zombie processes");
+        diag.note("see https://doc.rust-lang.org/stable/std/process/struct.Child.html#warning");
+    });
+}
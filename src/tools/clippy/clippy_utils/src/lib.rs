@@ -3077,6 +3077,16 @@ pub fn pat_is_wild<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx PatKind<'_>, body: i
     }
 }
 
+/// Returns `true` if the pattern is a `PatWild`, or is an ident that is not locally used, whether
+/// it's prefixed with `_` or not.
+pub fn pat_is_unused<'tcx>(cx: &LateContext<'tcx>, pat: &'tcx PatKind<'_>, body: impl Visitable<'tcx>) -> bool {
+    match *pat {
+        PatKind::Wild => true,
+        PatKind::Binding(_, id, _, None) => !visitors::is_local_used(cx, body, id),
+        _ => false,
+    }
+}
+
 #[derive(Clone, Copy)]
 pub enum RequiresSemi {
     Yes,
@@ -237,6 +237,10 @@ pub fn main() {
             exit(0);
         }
 
+        if let Some(format) = arg_value(&orig_args, "--print-renamed-lints", |_| true) {
+            exit(clippy_lints::print_renamed_lints(format));
+        }
+
         // Setting RUSTC_WRAPPER causes Cargo to pass 'rustc' as the first argument.
         // We're invoking the compiler programmatically, so we ignore this/
         let wrapper_mode = orig_args.get(1).map(Path::new).and_then(Path::file_stem) == Some("rustc".as_ref());
@@ -309,6 +313,7 @@ fn help_message() -> &'static str {
     <cyan,bold>-h</>, <cyan,bold>--help</>               Print this message
     <cyan,bold>-V</>, <cyan,bold>--version</>            Print version info and exit
     <cyan,bold>--rustc</>                  Pass all arguments to <cyan>rustc</>
+    <cyan,bold>--print-renamed-lints=json</> Print the table of renamed lints as JSON
 
 <green,bold>Allowing / Denying lints</>
 You can use tool lints to allow or deny lints from your code, e.g.:
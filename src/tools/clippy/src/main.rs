@@ -45,6 +45,25 @@ pub fn main() {
         return;
     }
 
+    // Like `clippy-driver`, accept both `--print-renamed-lints json` and
+    // `--print-renamed-lints=json`, but ignore the arguments after `--` which are passed on to the
+    // driver.
+    let args: Vec<String> = env::args().take_while(|a| a != "--").collect();
+    if let Some(pos) = args
+        .iter()
+        .position(|a| a == "--print-renamed-lints" || a.starts_with("--print-renamed-lints="))
+    {
+        if let Some(format) = args[pos]
+            .strip_prefix("--print-renamed-lints=")
+            .or_else(|| args.get(pos + 1).map(String::as_str))
+        {
+            process::exit(clippy_lints::print_renamed_lints(format));
+        } else {
+            show_help();
+        }
+        return;
+    }
+
     if let Err(code) = process(env::args().skip(2)) {
         process::exit(code);
     }
@@ -161,6 +180,7 @@ pub fn help_message() -> &'static str {
     <cyan,bold>-h</>, <cyan,bold>--help</>               Print this message
     <cyan,bold>-V</>, <cyan,bold>--version</>            Print version info and exit
     <cyan,bold>--explain [LINT]</>         Print the documentation for a given lint
+    <cyan,bold>--print-renamed-lints=json</> Print the table of renamed lints as JSON
 
 See all options with <cyan,bold>cargo check --help</>.
 
@@ -0,0 +1,26 @@
+#![feature(rustc_private)]
+
+use serde_json::Value;
+
+#[test]
+fn renamed_lints_json() {
+    let json: Value = serde_json::from_str(&clippy_lints::renamed_lints_json()).unwrap();
+    let renamed = json.as_array().unwrap();
+
+    assert!(!renamed.is_empty());
+    for lint in renamed {
+        let lint = lint.as_object().unwrap();
+        assert_eq!(lint.len(), 3, "unexpected fields in {lint:?}");
+        assert!(lint["old_name"].as_str().unwrap().starts_with("clippy::"));
+        assert!(lint["new_name"].is_string());
+        assert!(lint.contains_key("version"), "missing version in {lint:?}");
+        assert!(lint["version"].is_null() || lint["version"].is_string());
+    }
+
+    assert!(renamed.iter().any(|lint| {
+        lint["old_name"] == "clippy::blacklisted_name" && lint["new_name"] == "clippy::disallowed_names"
+    }));
+    assert!(renamed.iter().any(|lint| {
+        lint["old_name"] == "clippy::identity_conversion" && lint["version"] == "1.53.0"
+    }));
+}
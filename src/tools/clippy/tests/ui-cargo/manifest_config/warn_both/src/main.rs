@@ -0,0 +1,5 @@
+fn too_many(_: u8, _: u8, _: u8) {}
+
+fn main() {
+    too_many(1, 2, 3);
+}
@@ -0,0 +1,17 @@
+#![warn(clippy::blocking_in_async)]
+
+use std::process::Command;
+
+async fn run() {
+    let _ = Command::new("ls").output();
+    //~^ ERROR: call to blocking function `std::process::Command::output` in an async context
+    let _ = Command::new("ls").spawn().unwrap().wait();
+    //~^ ERROR: call to blocking function `std::process::Child::wait` in an async context
+}
+
+fn run_blocking() {
+    let _ = Command::new("ls").output();
+    let _ = Command::new("ls").spawn().unwrap().wait();
+}
+
+fn main() {}
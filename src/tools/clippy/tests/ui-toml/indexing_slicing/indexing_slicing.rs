@@ -0,0 +1,24 @@
+//@compile-flags: --test
+#![warn(clippy::indexing_slicing)]
+#![warn(clippy::out_of_bounds_indexing)]
+#![allow(unconditional_panic, clippy::no_effect, clippy::unnecessary_operation)]
+
+fn main() {
+    let x = [1, 2, 3, 4];
+    let index: usize = 1;
+    x[index];
+    //~^ ERROR: indexing may panic
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_fn() {
+        let x = [1, 2, 3, 4];
+        let index: usize = 1;
+        x[index];
+        // `out_of_bounds_indexing` is not affected by the configuration
+        [1, 2][5];
+        //~^ ERROR: index is out of bounds
+    }
+}
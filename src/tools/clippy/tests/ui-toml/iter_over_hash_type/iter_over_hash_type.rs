@@ -0,0 +1,35 @@
+#![warn(clippy::iter_over_hash_type)]
+
+pub struct OrderlessMap(Vec<(u32, u32)>);
+
+impl<'a> IntoIterator for &'a OrderlessMap {
+    type Item = &'a (u32, u32);
+    type IntoIter = std::slice::Iter<'a, (u32, u32)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+pub struct OrderedMap(Vec<(u32, u32)>);
+
+impl<'a> IntoIterator for &'a OrderedMap {
+    type Item = &'a (u32, u32);
+    type IntoIter = std::slice::Iter<'a, (u32, u32)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.iter()
+    }
+}
+
+fn main() {
+    let orderless = OrderlessMap(Vec::new());
+    for x in &orderless {
+        let _ = x;
+    }
+
+    let ordered = OrderedMap(Vec::new());
+    for x in &ordered {
+        let _ = x;
+    }
+}
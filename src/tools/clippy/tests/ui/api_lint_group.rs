@@ -0,0 +1,49 @@
+//@no-rustfix
+#![crate_type = "lib"]
+#![warn(clippy::api)]
+#![allow(dead_code)]
+
+pub mod retries {
+    mod inner {
+        pub struct Retries(u32);
+    }
+
+    pub use inner::Retries;
+    //~^ ERROR: using `pub use`
+
+    pub struct Config {
+        //~^ ERROR: exported structs should not be exhaustive
+        pub retries: u32,
+    }
+}
+
+/// Parses the number of retries.
+pub fn parse_retries(s: &str) -> Result<u32, std::num::ParseIntError> {
+    //~^ ERROR: docs for function returning `Result` missing `# Errors` section
+    s.parse()
+}
+
+/// Always fails.
+pub fn fail() {
+    //~^ ERROR: docs for function which may panic missing `# Panics` section
+    panic!("failed")
+}
+
+/// # Safety
+///
+/// There are no safety requirements.
+pub fn safe() {}
+//~^ ERROR: safe function's docs have unnecessary `# Safety` section
+
+pub struct RequestBuilder {
+    retries: u32,
+}
+
+impl RequestBuilder {
+    pub fn retries(mut self, retries: u32) -> Self {
+        //~^ ERROR: missing `#[must_use]` attribute on a builder method
+        self.retries = retries;
+        self
+    }
+    pub fn build(self) {}
+}
@@ -190,6 +190,12 @@ async fn dropped_before_await(x: std::sync::Mutex<u32>) {
     baz().await;
 }
 
+async fn held_in_tuple(x: &std::sync::Mutex<u32>) -> u32 {
+    let pair = (x.lock().unwrap(), 1);
+    //~^ ERROR: this `MutexGuard` is held across an await point
+    baz().await + pair.1
+}
+
 fn main() {
     let m = std::sync::Mutex::new(100);
     std_mutex::good(&m);
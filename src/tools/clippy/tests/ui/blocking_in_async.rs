@@ -0,0 +1,36 @@
+#![warn(clippy::blocking_in_async)]
+#![allow(clippy::manual_async_fn)]
+
+use std::future::Future;
+use std::net::TcpStream;
+use std::time::Duration;
+use std::{fs, thread};
+
+async fn read_file() -> std::io::Result<String> {
+    fs::read_to_string("file")
+    //~^ ERROR: call to blocking function `std::fs::read_to_string` in an async context
+}
+
+async fn sleep_and_connect() {
+    thread::sleep(Duration::from_secs(1));
+    //~^ ERROR: call to blocking function `std::thread::sleep` in an async context
+    let _ = TcpStream::connect("127.0.0.1:8080");
+    //~^ ERROR: call to blocking function `std::net::TcpStream::connect` in an async context
+}
+
+fn async_block() -> impl Future<Output = ()> {
+    async {
+        let _ = fs::File::open("file");
+        //~^ ERROR: call to blocking function `std::fs::File::open` in an async context
+
+        // Closures have their own body and may be run on another thread.
+        let read = || fs::read("file");
+    }
+}
+
+fn not_async() {
+    let _ = fs::read("file");
+    thread::sleep(Duration::from_secs(1));
+}
+
+fn main() {}
@@ -1,4 +1,4 @@
-#![allow(clippy::unnecessary_operation)]
+#![allow(clippy::unnecessary_operation, clippy::sliced_string_as_bytes)]
 #![warn(clippy::bytes_nth)]
 
 fn main() {
@@ -92,3 +92,24 @@ impl std::error::Error for Error {}
 mod h {
     type Error = std::fmt::Error;
 }
+
+// Do not lint `#[doc(hidden)]` types
+
+#[doc(hidden)]
+pub mod i {
+    #[derive(Debug)]
+    pub struct Error;
+
+    impl std::fmt::Display for Error {
+        fn fmt(&self, _: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            todo!()
+        }
+    }
+
+    impl std::error::Error for Error {}
+}
+
+pub mod j {
+    #[doc(hidden)]
+    pub type Error = std::fmt::Error;
+}
@@ -6,6 +6,26 @@ fn main() {
     //~^ ERROR: `format!(..)` appended to existing `String`
     string.push_str(&format!("{:?}", 5678));
     //~^ ERROR: `format!(..)` appended to existing `String`
+    // `write!(string, "{string}!")` would borrow `string` twice
+    string += &format!("{string}!");
+    //~^ ERROR: `format!(..)` appended to existing `String`
+}
+
+mod fixable {
+    pub fn push(s: &mut String) {
+        s.push_str(&format!("{}", 1234));
+        //~^ ERROR: `format!(..)` appended to existing `String`
+    }
+}
+
+mod already_imported {
+    use std::fmt::Write;
+
+    pub fn push(s: &mut String) {
+        let _ = write!(s, "{}", 1234);
+        s.push_str(&format!("{}", 5678));
+        //~^ ERROR: `format!(..)` appended to existing `String`
+    }
 }
 
 mod issue9493 {
@@ -0,0 +1,13 @@
+#![crate_type = "lib"]
+#![no_std]
+#![warn(clippy::format_push_string)]
+
+extern crate alloc;
+
+use alloc::format;
+use alloc::string::String;
+
+pub fn push(s: &mut String) {
+    s.push_str(&format!("{}", 1234));
+    //~^ ERROR: `format!(..)` appended to existing `String`
+}
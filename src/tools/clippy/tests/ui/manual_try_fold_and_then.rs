@@ -0,0 +1,11 @@
+#![allow(clippy::unnecessary_fold, unused)]
+#![warn(clippy::manual_try_fold)]
+
+fn main() {
+    let _ = [1, 2, 3]
+        .iter()
+        .fold(Ok::<i32, ()>(0), |acc, i| acc.and_then(|sum| Ok(sum + i)));
+    let _ = [1, 2, 3]
+        .iter()
+        .fold(Some(0i32), |acc, i| acc.and_then(|sum| sum.checked_add(*i)));
+}
@@ -0,0 +1,77 @@
+#![crate_type = "lib"]
+#![warn(clippy::missing_must_use_on_builder)]
+#![allow(dead_code)]
+
+pub struct Request;
+
+pub struct RequestBuilder {
+    retries: u32,
+}
+
+impl RequestBuilder {
+    pub fn retries(mut self, retries: u32) -> Self {
+        //~^ ERROR: missing `#[must_use]` attribute on a builder method
+        self.retries = retries;
+        self
+    }
+    pub fn set_retries(&mut self, retries: u32) -> &mut Self {
+        //~^ ERROR: missing `#[must_use]` attribute on a builder method
+        self.retries = retries;
+        self
+    }
+    #[must_use]
+    pub fn no_retries(mut self) -> Self {
+        self.retries = 0;
+        self
+    }
+    // There should be no warning here! (doesn't return the builder)
+    pub fn get_retries(&self) -> u32 {
+        self.retries
+    }
+    // There should be no warning here! (returns a shared reference)
+    pub fn as_ref(&self) -> &Self {
+        self
+    }
+    pub fn build(self) -> Request {
+        Request
+    }
+}
+
+// There should be no warning here! (not a builder, no `build` method)
+pub struct Point(u32);
+
+impl Point {
+    pub fn shifted(self) -> Self {
+        Self(self.0 + 1)
+    }
+}
+
+#[must_use]
+pub struct MustUseBuilder;
+
+impl MustUseBuilder {
+    // There should be no warning here! (`Self` is already `#[must_use]`)
+    pub fn with(self) -> Self {
+        self
+    }
+    // The attribute on the type doesn't apply to references
+    pub fn set(&mut self) -> &mut Self {
+        //~^ ERROR: missing `#[must_use]` attribute on a builder method
+        self
+    }
+    pub fn build(self) -> Request {
+        Request
+    }
+}
+
+// There should be no warning here! (not exported)
+struct PrivateBuilder;
+
+impl PrivateBuilder {
+    pub fn with(self) -> Self {
+        self
+    }
+    pub fn build(self) -> Request {
+        Request
+    }
+}
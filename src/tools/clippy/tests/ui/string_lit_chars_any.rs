@@ -23,6 +23,8 @@ fn main() {
     "\\.+*?()|[]{}^$#&-~".chars().any(|x| { x == c });
     // Do not lint
     NotStringLit.chars().any(|x| x == c);
+    "".chars().any(|x| x == c);
+    "abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ".chars().any(|x| x == c);
     "\\.+*?()|[]{}^$#&-~".chars().any(|x| {
         let c = 'c';
         x == c
@@ -68,7 +68,22 @@ fn into_huge(self) -> Box<Huge> {
     }
 }
 
+// don't lint: the box isn't created in this function
+fn bxed_from_elsewhere() -> Box<usize> {
+    bxed_usize()
+}
+
+// don't lint: this is used as a `fn` pointer with the signature it has
+fn bxed_fn_pointer() -> Box<usize> {
+    Box::new(9)
+}
+
+fn call_fn_pointer(f: fn() -> Box<usize>) -> usize {
+    *f()
+}
+
 fn main() {
     // don't lint: this is a closure
     let a = || -> Box<usize> { Box::new(5) };
+    call_fn_pointer(bxed_fn_pointer);
 }
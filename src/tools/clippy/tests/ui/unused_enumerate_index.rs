@@ -103,4 +103,9 @@ macro_rules! mac2 {
     let v = [1, 2, 3].iter().copied().enumerate();
     let x = v.map(|(_, x)| x).sum::<i32>();
     assert_eq!(x, 6);
+
+    // The index is bound, but never used
+    for (i, x) in [1, 2, 3].iter().enumerate() {
+        println!("{x}");
+    }
 }
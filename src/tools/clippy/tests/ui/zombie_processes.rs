@@ -0,0 +1,111 @@
+#![allow(clippy::needless_return)]
+
+use std::process::{Child, Command};
+
+fn main() {
+    {
+        // Check that #[expect] works
+        #[expect(clippy::zombie_processes)]
+        let mut x = Command::new("").spawn().unwrap();
+    }
+
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        //~^ ERROR: spawned process is never `wait()`ed on
+        x.kill();
+        x.id();
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        x.wait().unwrap(); // OK
+    }
+    {
+        let x = Command::new("").spawn().unwrap();
+        x.wait_with_output().unwrap(); // OK
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        x.try_wait().unwrap(); // OK
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        let r = &mut x;
+        r.wait().unwrap(); // OK, the child is waited on through `r`
+    }
+    {
+        let x = Command::new("").spawn().unwrap();
+        process_child(x); // OK, `process_child` might call `wait()` so assume it does
+    }
+    {
+        let mut x = Command::new("").spawn().unwrap();
+        let _ = move || x.wait(); // OK, the closure might be called
+    }
+
+    Command::new("").spawn().unwrap();
+    //~^ ERROR: spawned process is never `wait()`ed on
+    let _ = Command::new("").spawn().unwrap();
+    //~^ ERROR: spawned process is never `wait()`ed on
+    Command::new("").spawn().unwrap().wait().unwrap(); // OK
+}
+
+fn process_child(c: Child) {
+    todo!()
+}
+
+fn return_child() -> Child {
+    let x = Command::new("").spawn().unwrap();
+    x // OK, the caller is responsible for waiting
+}
+
+fn early_return(path: &str) -> std::io::Result<()> {
+    let mut x = Command::new("").spawn()?;
+    //~^ ERROR: spawned process is not `wait()`ed on in all code paths
+    std::fs::read_to_string(path)?;
+    x.wait()?;
+    Ok(())
+}
+
+fn return_child_early(cond: bool) -> Option<Child> {
+    let mut x = Command::new("").spawn().unwrap();
+    if cond {
+        return Some(x); // OK, this is a use of the child and not an early return
+    }
+    x.wait().unwrap();
+    None
+}
+
+fn closure_return() {
+    let mut x = Command::new("").spawn().unwrap();
+    let _ = || {
+        return; // OK, this doesn't return from `closure_return`
+    };
+    x.wait().unwrap();
+}
+
+fn missing_else(cond: bool) {
+    let mut x = Command::new("").spawn().unwrap();
+    //~^ ERROR: spawned process is not `wait()`ed on in all code paths
+    if cond {
+        x.wait().unwrap();
+    }
+}
+
+fn missing_wait_in_branch(cond: bool) {
+    let mut x = Command::new("").spawn().unwrap();
+    //~^ ERROR: spawned process is not `wait()`ed on in all code paths
+    if cond {
+        x.wait().unwrap();
+    } else {
+        println!("not waiting");
+    }
+}
+
+fn wait_in_both_branches(cond: bool) {
+    let mut x = Command::new("").spawn().unwrap();
+    if cond {
+        x.wait().unwrap(); // OK
+    } else {
+        x.kill().unwrap();
+        x.wait().unwrap(); // OK
+    }
+}
@@ -0,0 +1,106 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: {{sysroot-base}}
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+
+// Check that `evaluate_root_goal_with_region_constraints` only returns the region
+// constraints and region obligations added while evaluating the goal.
+
+#![feature(rustc_private)]
+
+extern crate rustc_driver;
+extern crate rustc_infer;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+extern crate rustc_span;
+extern crate rustc_trait_selection;
+
+use std::path::PathBuf;
+
+use rustc_infer::infer::region_constraints::Constraint;
+use rustc_infer::infer::{RegionVariableOrigin, SubregionOrigin, TyCtxtInferExt};
+use rustc_infer::traits::solve::{Certainty, Goal};
+use rustc_interface::interface;
+use rustc_middle::ty::{self, Ty};
+use rustc_session::config::{Input, Options};
+use rustc_span::{FileName, DUMMY_SP};
+use rustc_trait_selection::solve::InferCtxtEvalExt;
+
+fn main() {
+    let src = r#"
+    fn main() {}
+    "#;
+
+    let args: Vec<String> = std::env::args().collect();
+    let sysroot = PathBuf::from(args.get(1).expect("expected sysroot"));
+
+    let mut opts = Options::default();
+    opts.maybe_sysroot = Some(sysroot);
+
+    let name = FileName::anon_source_code(src);
+    let input = Input::Str { name, input: src.to_string() };
+
+    let config = interface::Config {
+        opts,
+        crate_cfg: Default::default(),
+        crate_check_cfg: Default::default(),
+        input,
+        output_file: None,
+        output_dir: None,
+        ice_file: None,
+        file_loader: None,
+        locale_resources: &[],
+        lint_caps: Default::default(),
+        psess_created: None,
+        hash_untracked_state: None,
+        register_lints: None,
+        override_queries: None,
+        make_codegen_backend: None,
+        registry: rustc_driver::diagnostics_registry(),
+        using_internal_features: std::sync::Arc::default(),
+        expanded_args: Default::default(),
+    };
+
+    interface::run_compiler(config, |compiler| {
+        compiler.enter(|queries| {
+            queries.global_ctxt().unwrap().enter(|tcx| {
+                let infcx = tcx.infer_ctxt().with_next_trait_solver(true).build();
+                let param_env = ty::ParamEnv::empty();
+                let origin = RegionVariableOrigin::MiscVariable(DUMMY_SP);
+                let a = infcx.next_region_var(origin);
+                let b = infcx.next_region_var(origin);
+                let c = infcx.next_region_var(origin);
+
+                // A constraint which is unrelated to the goals below.
+                infcx.sub_regions(SubregionOrigin::RelateRegionParamBound(DUMMY_SP, None), a, c);
+
+                let eval = infcx
+                    .evaluate_root_goal_with_region_constraints(Goal::new(
+                        tcx,
+                        param_env,
+                        ty::OutlivesPredicate(a, b),
+                    ))
+                    .unwrap();
+                assert_eq!(eval.certainty, Certainty::Yes);
+                assert_eq!(eval.response.value.certainty, Certainty::Yes);
+                assert_eq!(eval.region_constraints.constraints.len(), 1);
+                let (constraint, _) = &eval.region_constraints.constraints[0];
+                assert!(matches!(constraint, Constraint::VarSubVar(..)));
+                assert!(eval.region_obligations.is_empty());
+
+                let ref_ty = Ty::new_imm_ref(tcx, c, tcx.types.u8);
+                let eval = infcx
+                    .evaluate_root_goal_with_region_constraints(Goal::new(
+                        tcx,
+                        param_env,
+                        ty::OutlivesPredicate(ref_ty, b),
+                    ))
+                    .unwrap();
+                assert_eq!(eval.certainty, Certainty::Yes);
+                assert_eq!(eval.region_obligations.len(), 1);
+                assert_eq!(eval.region_obligations[0].sup_type, ref_ty);
+            });
+        });
+    });
+}
@@ -0,0 +1,118 @@
+//@ edition: 2021
+//@ run-pass
+//@ run-flags: {{sysroot-base}}
+//@ ignore-stage1 (requires matching sysroot built with in-tree compiler)
+
+// Check that the region constraints of a canonical response are transitively reduced,
+// i.e. that they never contain `'a: 'c` together with `'a: 'b` and `'b: 'c`.
+
+#![feature(rustc_private)]
+
+extern crate rustc_data_structures;
+extern crate rustc_driver;
+extern crate rustc_infer;
+extern crate rustc_interface;
+extern crate rustc_middle;
+extern crate rustc_session;
+extern crate rustc_span;
+extern crate rustc_trait_selection;
+
+use std::path::PathBuf;
+
+use rustc_data_structures::fx::FxHashSet;
+use rustc_infer::infer::{RegionVariableOrigin, TyCtxtInferExt};
+use rustc_infer::traits::solve::{Certainty, Goal};
+use rustc_interface::interface;
+use rustc_middle::ty;
+use rustc_session::config::{Input, Options};
+use rustc_span::{FileName, Symbol, DUMMY_SP};
+use rustc_trait_selection::solve::InferCtxtEvalExt;
+
+fn main() {
+    let src = r#"
+    pub trait Trait<'a, 'b, 'c> {}
+    impl<'a: 'b, 'b: 'c, 'c> Trait<'a, 'b, 'c> for () where 'a: 'c {}
+    fn main() {}
+    "#;
+
+    let args: Vec<String> = std::env::args().collect();
+    let sysroot = PathBuf::from(args.get(1).expect("expected sysroot"));
+
+    let mut opts = Options::default();
+    opts.maybe_sysroot = Some(sysroot);
+
+    let name = FileName::anon_source_code(src);
+    let input = Input::Str { name, input: src.to_string() };
+
+    let config = interface::Config {
+        opts,
+        crate_cfg: Default::default(),
+        crate_check_cfg: Default::default(),
+        input,
+        output_file: None,
+        output_dir: None,
+        ice_file: None,
+        file_loader: None,
+        locale_resources: &[],
+        lint_caps: Default::default(),
+        psess_created: None,
+        hash_untracked_state: None,
+        register_lints: None,
+        override_queries: None,
+        make_codegen_backend: None,
+        registry: rustc_driver::diagnostics_registry(),
+        using_internal_features: std::sync::Arc::default(),
+        expanded_args: Default::default(),
+    };
+
+    interface::run_compiler(config, |compiler| {
+        compiler.enter(|queries| {
+            queries.global_ctxt().unwrap().enter(|tcx| {
+                let trait_def_id = tcx
+                    .hir()
+                    .items()
+                    .map(|id| id.owner_id.to_def_id())
+                    .find(|&def_id| tcx.opt_item_name(def_id) == Some(Symbol::intern("Trait")))
+                    .expect("expected `Trait` to be defined");
+
+                let infcx = tcx.infer_ctxt().with_next_trait_solver(true).build();
+                let origin = RegionVariableOrigin::MiscVariable(DUMMY_SP);
+                let a = infcx.next_region_var(origin);
+                let b = infcx.next_region_var(origin);
+                let c = infcx.next_region_var(origin);
+
+                let trait_ref = ty::TraitRef::new(
+                    tcx,
+                    trait_def_id,
+                    [tcx.types.unit.into(), a.into(), b.into(), c.into()],
+                );
+                let eval = infcx
+                    .evaluate_root_goal_with_region_constraints(Goal::new(
+                        tcx,
+                        ty::ParamEnv::empty(),
+                        trait_ref,
+                    ))
+                    .unwrap();
+                assert_eq!(eval.certainty, Certainty::Yes);
+
+                let edges: FxHashSet<_> = eval
+                    .response
+                    .value
+                    .external_constraints
+                    .region_constraints
+                    .iter()
+                    .filter_map(|&ty::OutlivesPredicate(arg, sub)| Some((arg.as_region()?, sub)))
+                    .collect();
+                assert!(!edges.is_empty());
+                for &(sup, sub) in &edges {
+                    assert!(
+                        !edges.iter().any(|&(mid_sup, mid)| {
+                            mid_sup == sup && mid != sub && edges.contains(&(mid, sub))
+                        }),
+                        "`{sup:?}: {sub:?}` is implied by other constraints in {edges:?}",
+                    );
+                }
+            });
+        });
+    });
+}
@@ -0,0 +1,29 @@
+// Check that trait goals whose self type is an inherent associated
+// type get normalized before assembling candidates.
+//@ check-pass
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+
+#![feature(inherent_associated_types)]
+#![allow(incomplete_features)]
+
+struct Wrapper<T>(T);
+
+impl<T> Wrapper<T> {
+    type Inner = T;
+}
+
+fn impls_clone<T: Clone>() {}
+
+fn where_clause()
+where
+    Wrapper<u32>::Inner: Clone,
+{
+}
+
+fn main() {
+    impls_clone::<Wrapper<u32>::Inner>();
+    impls_clone::<Wrapper<String>::Inner>();
+    where_clause();
+}
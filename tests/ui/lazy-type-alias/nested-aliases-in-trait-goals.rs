@@ -0,0 +1,28 @@
+// Check that nested lazy type aliases in the self type of trait goals
+// are normalized, registering the where-clauses of each alias.
+
+//@ check-pass
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+
+#![feature(lazy_type_alias)]
+#![allow(incomplete_features)]
+
+type Alias<T: Clone> = Vec<T>;
+type Nested<T: Clone> = Alias<Alias<T>>;
+
+trait Trait {}
+impl<T> Trait for Vec<T> {}
+
+fn impls_trait<T: Trait>() {}
+
+fn generic<T: Clone>() {
+    impls_trait::<Alias<T>>();
+    impls_trait::<Nested<T>>();
+    let _: Nested<T> = Vec::<Vec<T>>::new();
+}
+
+fn main() {
+    generic::<u8>();
+}
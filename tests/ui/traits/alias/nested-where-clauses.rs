@@ -0,0 +1,33 @@
+//@ check-pass
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+
+// Check that the where-clauses of nested trait aliases are required when
+// proving the outer alias, and that the ones on `Self` are implied by it.
+
+#![feature(trait_alias)]
+
+trait Inner<T> = Clone where T: Send, Self: Default;
+trait Middle<T> = Inner<T> where Self: Sync;
+trait Outer<T> = Middle<T> + Send;
+
+fn requires_outer<T: Send, U: Outer<T>>() {}
+
+fn proves_outer() {
+    requires_outer::<u32, Vec<u8>>();
+}
+
+fn is_send<T: Send>() {}
+fn is_sync<T: Sync>() {}
+
+fn assumes_outer<T: Send, U: Outer<T>>() -> U {
+    is_send::<U>();
+    is_sync::<U>();
+    U::default().clone()
+}
+
+fn main() {
+    proves_outer();
+    let _: String = assumes_outer::<u32, String>();
+}
@@ -0,0 +1,23 @@
+//@ compile-flags: -Znext-solver -Zdump-solver-proof-trees
+//@ check-pass
+//@ dont-check-compiler-stderr
+//@ error-pattern: goal u32: Trait (source: Misc): yes
+//@ error-pattern: candidate Impl(
+//@ error-pattern: goal u32: Bound (source: ImplWhereBound): yes
+
+// Check that `-Zdump-solver-proof-trees` prints the candidates of resolved root goals
+// and their nested goals.
+
+#![crate_type = "lib"]
+
+trait Bound {}
+impl Bound for u32 {}
+
+trait Trait {}
+impl<T: Bound> Trait for T {}
+
+fn needs_trait<T: Trait>() {}
+
+pub fn foo() {
+    needs_trait::<u32>();
+}
@@ -0,0 +1,23 @@
+//@ compile-flags: -Znext-solver
+//@ check-pass
+
+// Check that the builtin `FnPtr` candidate applies to all kinds of
+// function pointers, including higher-ranked and `unsafe extern` ones.
+
+#![feature(fn_ptr_trait)]
+
+use std::marker::FnPtr;
+
+fn is_fn_ptr<T: FnPtr>() {}
+
+fn addr<T: FnPtr>(f: T) -> *const () {
+    f.addr()
+}
+
+fn main() {
+    is_fn_ptr::<fn()>();
+    is_fn_ptr::<fn(u32) -> u32>();
+    is_fn_ptr::<for<'a> fn(&'a u8) -> &'a u8>();
+    is_fn_ptr::<unsafe extern "C" fn(i32)>();
+    let _ = addr(main as fn());
+}
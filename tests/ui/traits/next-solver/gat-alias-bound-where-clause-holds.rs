@@ -0,0 +1,21 @@
+//@ compile-flags: -Znext-solver
+//@ check-pass
+
+// Using the item bounds of a GAT requires its where-clauses to hold. Check that
+// this is the case for the usual lending iterator, where `Self: 'a` is implied
+// by the reference to the iterator.
+
+trait LendingIterator {
+    type Item<'a>: Copy
+    where
+        Self: 'a;
+
+    fn next<'a>(&'a mut self) -> Option<Self::Item<'a>>;
+}
+
+fn next_twice<'a, I: LendingIterator>(iter: &'a mut I) -> Option<(I::Item<'a>, I::Item<'a>)> {
+    let item = iter.next()?;
+    Some((item, item))
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//@ compile-flags: -Znext-solver
+
+// The item bounds of a GAT only hold if its where-clauses hold, so the `Copy`
+// alias bound of `X::Assoc<String>` cannot be used as `String: Copy` does not hold.
+
+trait Trait {
+    type Assoc<T>: Copy
+    where
+        T: Copy;
+}
+
+fn is_copy<T: Copy>() {}
+
+fn foo<X: Trait>() {
+    is_copy::<X::Assoc<String>>();
+    //~^ ERROR the trait bound `String: Copy` is not satisfied
+    //~| ERROR the trait bound `String: Copy` is not satisfied
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+//@ compile-flags: -Znext-solver
+//@ check-pass
+
+// Check that negative goals can be proven using negative impls
+// and negative where-bounds.
+
+#![feature(negative_bounds, negative_impls)]
+
+trait Trait {}
+
+struct NotImplemented;
+impl !Trait for NotImplemented {}
+
+struct NotCopyable;
+impl !Copy for NotCopyable {}
+
+fn not_trait<T: !Trait>() {}
+fn not_copy<T: !Copy>() {}
+
+fn neg_param_env<T: !Trait + !Copy>() {
+    not_trait::<T>();
+    not_copy::<T>();
+}
+
+fn neg_impl() {
+    not_trait::<NotImplemented>();
+    not_copy::<NotCopyable>();
+}
+
+fn main() {}
@@ -0,0 +1,20 @@
+//@ compile-flags: -Znext-solver
+
+// When merging the candidates of a goal fails, we only report overflow if all
+// candidates overflowed. Here the impl for `Trait<u16>` holds, so the goal is
+// ambiguous rather than overflowing, even though the other impl overflows.
+
+trait Overflow {}
+struct W<T>(T);
+impl<T> Overflow for T where W<T>: Overflow {}
+
+trait Trait<U> {}
+impl Trait<u8> for u32 where u32: Overflow {}
+impl Trait<u16> for u32 {}
+
+fn impls_trait<T: Trait<U>, U>() {}
+
+fn main() {
+    impls_trait::<u32, _>();
+    //~^ ERROR type annotations needed
+}
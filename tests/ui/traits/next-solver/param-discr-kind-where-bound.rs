@@ -0,0 +1,27 @@
+//@ compile-flags: -Znext-solver
+//@ check-pass
+
+// Check that `<T as DiscriminantKind>::Discriminant` for params and rigid
+// aliases is normalized using where-bounds and alias bounds, as the builtin
+// candidate does not apply to them.
+
+#![feature(discriminant_kind)]
+
+use std::marker::DiscriminantKind;
+
+trait Trait {
+    type Assoc: DiscriminantKind<Discriminant = u32>;
+}
+
+fn is_u8(_: u8) {}
+fn is_u32(_: u32) {}
+
+fn param<T: DiscriminantKind<Discriminant = u8>>(x: <T as DiscriminantKind>::Discriminant) {
+    is_u8(x);
+}
+
+fn alias_bound<T: Trait>(x: <T::Assoc as DiscriminantKind>::Discriminant) {
+    is_u32(x);
+}
+
+fn main() {}
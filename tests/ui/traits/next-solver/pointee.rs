@@ -1,12 +1,16 @@
 //@ compile-flags: -Znext-solver
 //@ check-pass
-#![feature(ptr_metadata)]
+#![feature(ptr_metadata, extern_types)]
 
 use std::ptr::{DynMetadata, Pointee};
 
 trait Trait<U> {}
 struct MyDst<T: ?Sized>(T);
 
+extern "C" {
+    type Extern;
+}
+
 fn meta_is<T: Pointee<Metadata = U> + ?Sized, U>() {}
 
 fn works<T>() {
@@ -16,6 +20,10 @@ fn works<T>() {
     meta_is::<dyn Trait<T>, DynMetadata<dyn Trait<T>>>();
     meta_is::<MyDst<T>, ()>();
     meta_is::<((((([u8],),),),),), usize>();
+    meta_is::<Extern, ()>();
+    meta_is::<MyDst<MyDst<MyDst<str>>>, usize>();
+    meta_is::<MyDst<(u8, MyDst<dyn Trait<T>>)>, DynMetadata<dyn Trait<T>>>();
+    meta_is::<MyDst<MyDst<Extern>>, ()>();
 }
 
 fn main() {}
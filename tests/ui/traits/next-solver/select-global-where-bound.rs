@@ -0,0 +1,32 @@
+//@ compile-flags: -Znext-solver
+//@ check-pass
+
+// Unsizing coercions select `CoerceUnsized` and `Unsize` goals and fail if selection
+// is ambiguous. A global where-bound like `Box<u32>: CoerceUnsized<..>` does not
+// mention any generic parameters, so we have to select the impl instead, while a
+// where-bound such as `Box<T>: CoerceUnsized<..>` is preferred over the impl.
+
+#![feature(coerce_unsized)]
+#![allow(trivial_bounds)]
+
+use std::fmt::Debug;
+use std::ops::CoerceUnsized;
+
+fn coerce_global(x: Box<u32>) -> Box<dyn Debug>
+where
+    Box<u32>: CoerceUnsized<Box<dyn Debug>>,
+{
+    x
+}
+
+fn coerce_param<T: Debug + 'static>(x: Box<T>) -> Box<dyn Debug>
+where
+    Box<T>: CoerceUnsized<Box<dyn Debug>>,
+{
+    x
+}
+
+fn main() {
+    coerce_global(Box::new(1));
+    coerce_param(Box::new("hi"));
+}
@@ -0,0 +1,20 @@
+//@ compile-flags: -Znext-solver -Zself-profile={{build-base}} -Zself-profile-events=default,args
+//@ check-pass
+
+// Check that recording the trait path of the candidate assembly activities
+// doesn't ICE when compiling without emitting any diagnostics.
+
+#![crate_type = "lib"]
+
+trait Bound {}
+impl Bound for u32 {}
+
+trait Trait {}
+impl<T: Bound> Trait for T {}
+
+fn needs_trait<T: Trait>() {}
+
+pub fn foo<T: Trait>() {
+    needs_trait::<u32>();
+    needs_trait::<T>();
+}
@@ -0,0 +1,36 @@
+//@ compile-flags: -Znext-solver
+
+// Check that types whose last field is unsized, as well as extern types, are not `Sized`.
+
+#![feature(extern_types)]
+
+trait Trait {
+    type Assoc: ?Sized;
+}
+
+impl Trait for i32 {
+    type Assoc = str;
+}
+
+extern "C" {
+    type Foreign;
+}
+
+struct Tail<T: ?Sized>(u32, T);
+
+struct ProjTail<T: Trait>(u32, T::Assoc);
+
+fn is_sized<T>() {}
+
+fn main() {
+    is_sized::<Foreign>();
+    //~^ ERROR the size for values of type `Foreign` cannot be known at compilation time
+    is_sized::<Tail<[u8]>>();
+    //~^ ERROR the size for values of type `[u8]` cannot be known at compilation time
+    is_sized::<Tail<dyn Trait<Assoc = u8>>>();
+    //~^ ERROR the size for values of type `(dyn Trait<Assoc = u8> + 'static)` cannot be known
+    is_sized::<(u8, str)>();
+    //~^ ERROR the size for values of type `str` cannot be known at compilation time
+    is_sized::<ProjTail<i32>>();
+    //~^ ERROR the size for values of type `str` cannot be known at compilation time
+}
@@ -0,0 +1,38 @@
+//@ compile-flags: -Znext-solver
+//@ check-pass
+
+// Check that `Sized` for tuples and ADTs only depends on their last field,
+// including when that field is a projection.
+
+trait Trait {
+    type Assoc: ?Sized;
+}
+
+impl Trait for u32 {
+    type Assoc = u8;
+}
+
+impl Trait for i32 {
+    type Assoc = str;
+}
+
+struct Tail<T: ?Sized>(u32, T);
+
+struct ProjTail<T: Trait>(u32, T::Assoc);
+
+fn is_sized<T>() {}
+
+fn generic<T: Trait<Assoc: Sized>>() {
+    is_sized::<ProjTail<T>>();
+    is_sized::<(u8, T::Assoc)>();
+}
+
+fn main() {
+    is_sized::<()>();
+    is_sized::<(u8, [u8; 4])>();
+    is_sized::<Tail<u8>>();
+    is_sized::<Tail<Tail<(u8, u16)>>>();
+    is_sized::<ProjTail<u32>>();
+    is_sized::<(Tail<u8>, <u32 as Trait>::Assoc)>();
+    generic::<u32>();
+}
@@ -0,0 +1,27 @@
+//@ check-pass
+//@ revisions: current next
+//@ ignore-compare-mode-next-solver (explicit revisions)
+//@[next] compile-flags: -Znext-solver
+
+// Check that we can drop auto traits and shorten the region of a trait
+// object in the same unsizing coercion, both when upcasting to a supertrait
+// and when keeping the same principal.
+
+#![feature(trait_upcasting)]
+
+trait Super {}
+trait Sub: Super {}
+
+fn drop_auto_traits<'a: 'b, 'b>(x: Box<dyn Sub + Send + Sync + 'a>) -> Box<dyn Sub + 'b> {
+    x
+}
+
+fn drop_some_auto_traits<'a: 'b, 'b>(x: &(dyn Sub + Send + Sync + 'a)) -> &(dyn Sub + Sync + 'b) {
+    x
+}
+
+fn upcast_and_drop_auto_traits<'a: 'b, 'b>(x: &(dyn Sub + Send + 'a)) -> &(dyn Super + 'b) {
+    x
+}
+
+fn main() {}